@@ -0,0 +1,67 @@
+//! Generates one test per part of every fixture in `fixtures/day<N>/`, so
+//! adding an example only takes dropping a fixture file there.
+
+use std::{env, fmt::Write, fs, path::Path};
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("cargo sets CARGO_MANIFEST_DIR");
+    let fixtures_dir = Path::new(&manifest_dir).join("fixtures");
+    println!("cargo:rerun-if-changed={}", fixtures_dir.display());
+
+    let mut fixtures = Vec::new();
+    for day_dir in fs::read_dir(&fixtures_dir).into_iter().flatten().flatten() {
+        println!("cargo:rerun-if-changed={}", day_dir.path().display());
+        let Some(day) = day_dir
+            .file_name()
+            .to_str()
+            .and_then(|name| name.strip_prefix("day"))
+            .and_then(|day| day.parse::<u8>().ok())
+        else {
+            continue;
+        };
+        for file in fs::read_dir(day_dir.path()).into_iter().flatten().flatten() {
+            let path = file.path();
+            if path.extension().is_some_and(|extension| extension == "txt") {
+                fixtures.push((day, path));
+            }
+        }
+    }
+    fixtures.sort();
+
+    let mut tests = String::new();
+    for (day, path) in fixtures {
+        println!("cargo:rerun-if-changed={}", path.display());
+        let name = path.file_stem().unwrap().to_str().unwrap();
+        let text = fs::read_to_string(&path).unwrap();
+        for line in text.lines().take_while(|line| *line != "== input ==") {
+            let Some(header) = line
+                .strip_prefix("== part ")
+                .and_then(|header| header.strip_suffix(" =="))
+            else {
+                continue;
+            };
+            let (part, ignored) = match header.strip_suffix(" (ignored)") {
+                Some(part) => (part, true),
+                None => (header, false),
+            };
+            let test_name: String = format!("day{}_{}_part{}", day, name, part)
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                .collect();
+            writeln!(
+                tests,
+                "#[test]{}\nfn {}() {{\n    crate::fixture::check({}, {:?}, include_str!({:?}), {});\n}}",
+                if ignored { "\n#[ignore]" } else { "" },
+                test_name,
+                day,
+                name,
+                path.display().to_string(),
+                part,
+            )
+            .unwrap();
+        }
+    }
+
+    let out_dir = env::var("OUT_DIR").expect("cargo sets OUT_DIR");
+    fs::write(Path::new(&out_dir).join("fixture_tests.rs"), tests).unwrap();
+}
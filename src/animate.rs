@@ -0,0 +1,241 @@
+use std::{
+    io::{self, Write},
+    time::{Duration, Instant},
+};
+
+use crossterm::{
+    cursor::{Hide, MoveTo, Show},
+    event::{self, Event, KeyCode, KeyEventKind},
+    queue,
+    style::Print,
+    terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+};
+
+use crate::visualize::Simulation;
+
+/// What a key asks of a running animation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    TogglePause,
+    /// Advance by one frame while paused.
+    Step,
+    Faster,
+    Slower,
+    Quit,
+}
+
+impl Control {
+    pub fn from_key(key: KeyCode) -> Option<Control> {
+        match key {
+            KeyCode::Char(' ') | KeyCode::Char('p') => Some(Control::TogglePause),
+            KeyCode::Char('n') | KeyCode::Char('.') | KeyCode::Right => Some(Control::Step),
+            KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Up => Some(Control::Faster),
+            KeyCode::Char('-') | KeyCode::Down => Some(Control::Slower),
+            KeyCode::Char('q') | KeyCode::Esc => Some(Control::Quit),
+            _ => None,
+        }
+    }
+}
+
+pub const MAX_FRAMES_PER_SECOND: u32 = 1000;
+
+/// How to play an animation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnimateOptions {
+    pub frames_per_second: u32,
+    /// Steps of the simulation per frame.
+    pub every: usize,
+    /// Steps after which the simulation is stopped.
+    pub limit: Option<usize>,
+}
+
+/// The state of an animation between frames.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Player {
+    frames_per_second: u32,
+    paused: bool,
+    steps: usize,
+    is_over: bool,
+}
+
+impl Player {
+    /// Applies `control`, returning whether to advance by a frame right away.
+    fn apply(&mut self, control: Control) -> bool {
+        match control {
+            Control::TogglePause => self.paused = !self.paused || self.is_over,
+            Control::Step => return self.paused && !self.is_over,
+            Control::Faster => {
+                self.frames_per_second = (self.frames_per_second * 2).min(MAX_FRAMES_PER_SECOND)
+            }
+            Control::Slower => self.frames_per_second = (self.frames_per_second / 2).max(1),
+            Control::Quit => {}
+        }
+        false
+    }
+
+    fn frame_duration(&self) -> Duration {
+        Duration::from_secs(1) / self.frames_per_second
+    }
+
+    /// Advances `simulation` by a frame, stopping at the end of the
+    /// simulation or at `options.limit`.
+    fn advance(&mut self, simulation: &mut dyn Simulation, options: &AnimateOptions) {
+        for _ in 0..options.every {
+            if options.limit == Some(self.steps) || !simulation.step() {
+                self.is_over = true;
+                self.paused = true;
+                return;
+            }
+            self.steps += 1;
+        }
+    }
+
+    fn status(&self) -> String {
+        let state = if self.is_over {
+            "finished"
+        } else if self.paused {
+            "paused"
+        } else {
+            "running"
+        };
+        format!(
+            "step {} | {} fps | {} | space: pause, n: step, +/-: speed, q: quit",
+            self.steps, self.frames_per_second, state
+        )
+    }
+}
+
+/// The top left corner of `text` that fits in `width` columns and `height`
+/// rows.
+fn crop(text: &str, width: usize, height: usize) -> Vec<String> {
+    text.lines()
+        .take(height)
+        .map(|line| line.chars().take(width).collect())
+        .collect()
+}
+
+/// Switches the terminal to a raw alternate screen for as long as it lives.
+struct Screen;
+
+impl Screen {
+    fn enter(out: &mut impl Write) -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        let screen = Screen;
+        queue!(out, EnterAlternateScreen, Hide)?;
+        out.flush()?;
+        Ok(screen)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let mut out = io::stdout();
+        // Nothing better to do when the terminal cannot be restored.
+        let _ = queue!(out, Show, LeaveAlternateScreen);
+        let _ = out.flush();
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn draw(out: &mut impl Write, text: &str, status: &str) -> io::Result<()> {
+    let (columns, rows) = terminal::size()?;
+    let (width, height) = (columns as usize, (rows as usize).saturating_sub(1));
+    queue!(out, Clear(ClearType::All))?;
+    for (row, line) in crop(text, width, height).into_iter().enumerate() {
+        queue!(out, MoveTo(0, row as u16), Print(line))?;
+    }
+    let status: String = status.chars().take(width).collect();
+    queue!(out, MoveTo(0, rows.saturating_sub(1)), Print(status))?;
+    out.flush()
+}
+
+/// Plays `simulation` in the terminal, redrawing its `Display` output in
+/// place every frame until the user quits.
+pub fn animate(simulation: &mut dyn Simulation, options: &AnimateOptions) -> io::Result<()> {
+    let mut out = io::stdout();
+    let _screen = Screen::enter(&mut out)?;
+    let mut player = Player {
+        frames_per_second: options.frames_per_second.clamp(1, MAX_FRAMES_PER_SECOND),
+        paused: false,
+        steps: 0,
+        is_over: false,
+    };
+    let mut next_frame = Instant::now() + player.frame_duration();
+    loop {
+        draw(&mut out, &simulation.to_string(), &player.status())?;
+        let mut advance = false;
+        let timeout = next_frame.saturating_duration_since(Instant::now());
+        let has_event = if player.paused {
+            true
+        } else {
+            event::poll(timeout)?
+        };
+        if has_event {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    match Control::from_key(key.code) {
+                        Some(Control::Quit) => return Ok(()),
+                        Some(control) => advance = player.apply(control),
+                        None => {}
+                    }
+                }
+            }
+        }
+        if !player.paused && Instant::now() >= next_frame {
+            advance = true;
+            next_frame = Instant::now() + player.frame_duration();
+        }
+        if advance {
+            player.advance(simulation, options);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn player() -> Player {
+        Player {
+            frames_per_second: 10,
+            paused: false,
+            steps: 0,
+            is_over: false,
+        }
+    }
+
+    #[test]
+    fn keys_pause_step_and_change_speed() {
+        let mut player = player();
+        assert!(!player.apply(Control::Step));
+        assert!(!player.apply(Control::from_key(KeyCode::Char(' ')).unwrap()));
+        assert!(player.paused);
+        assert!(player.apply(Control::Step));
+        player.apply(Control::Faster);
+        assert_eq!(player.frame_duration(), Duration::from_millis(50));
+        player.apply(Control::Slower);
+        player.apply(Control::Slower);
+        player.apply(Control::Slower);
+        player.apply(Control::Slower);
+        assert_eq!(player.frames_per_second, 1);
+        assert_eq!(Control::from_key(KeyCode::Char('x')), None);
+    }
+
+    #[test]
+    fn finished_animations_stay_paused() {
+        let mut player = Player {
+            paused: true,
+            is_over: true,
+            ..player()
+        };
+        player.apply(Control::TogglePause);
+        assert!(player.paused);
+        assert!(!player.apply(Control::Step));
+    }
+
+    #[test]
+    fn frames_are_cropped_to_the_terminal() {
+        assert_eq!(crop("abc\ndef\nghi\n", 2, 2), ["ab", "de"]);
+        assert_eq!(crop("a\n", 5, 5), ["a"]);
+    }
+}
@@ -0,0 +1,166 @@
+use std::{fmt::Display, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    solution::{Answer, Failure},
+    store::{self, StoreError},
+};
+
+/// The expected answer of one part of a day for one named input.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KnownAnswer {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub expected: String,
+}
+
+/// The checked-in answers that solutions are verified against.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct KnownAnswers(Vec<KnownAnswer>);
+
+impl KnownAnswers {
+    /// Reads the answers stored at `path`; a missing file holds no answers.
+    pub fn load(path: &Path) -> Result<Self, StoreError> {
+        Ok(KnownAnswers(store::load(path)?.unwrap_or_default()))
+    }
+
+    /// Writes the answers to `path`, sorted by day, part and input.
+    pub fn save(&mut self, path: &Path) -> Result<(), StoreError> {
+        self.0
+            .sort_by(|a, b| (a.day, a.part, &a.input).cmp(&(b.day, b.part, &b.input)));
+        store::save(path, &self.0)
+    }
+
+    pub fn get(&self, day: u8, part: u8, input: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|known| known.day == day && known.part == part && known.input == input)
+            .map(|known| known.expected.as_str())
+    }
+
+    /// Records `answer` as expected, replacing any previous expectation.
+    pub fn record(&mut self, day: u8, part: u8, input: &str, answer: &Answer) {
+        self.0
+            .retain(|known| !(known.day == day && known.part == part && known.input == input));
+        self.0.push(KnownAnswer {
+            day,
+            part,
+            input: input.to_string(),
+            expected: answer.to_string(),
+        });
+    }
+
+    /// Every known answer, in file order.
+    pub fn iter(&self) -> impl Iterator<Item = &KnownAnswer> {
+        self.0.iter()
+    }
+}
+
+/// How a part's answer compares to the known answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// No answer is known yet for a solved part.
+    Missing {
+        actual: String,
+    },
+    /// The part is unsolved and no answer is known.
+    Unsolved,
+    Error(Failure),
+}
+
+impl Verdict {
+    /// Compares the displayed `answer` with `expected`, ignoring trailing line
+    /// breaks, which multi-line answers such as drawn screens end with.
+    pub fn new(expected: Option<&str>, answer: &Result<Answer, Failure>) -> Self {
+        match (expected, answer) {
+            (_, Err(failure)) => Verdict::Error(failure.clone()),
+            (None, Ok(Answer::Unsolved)) => Verdict::Unsolved,
+            (None, Ok(answer)) => Verdict::Missing {
+                actual: answer.to_string(),
+            },
+            (Some(expected), Ok(answer)) => {
+                let actual = answer.to_string();
+                if actual.trim_end_matches('\n') == expected.trim_end_matches('\n') {
+                    Verdict::Pass
+                } else {
+                    Verdict::Fail {
+                        expected: expected.to_string(),
+                        actual,
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Verdict::Fail { .. } | Verdict::Error(_))
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => f.write_str("pass"),
+            Verdict::Fail { expected, actual } => {
+                write!(f, "FAIL (expected {:?}, got {:?})", expected, actual)
+            }
+            Verdict::Missing { actual } => write!(f, "missing (got {:?})", actual),
+            Verdict::Unsolved => f.write_str("unsolved"),
+            Verdict::Error(failure) => write!(f, "FAIL ({})", failure),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn verdicts_compare_displayed_answers() {
+        assert_eq!(Verdict::new(Some("24"), &Ok(24.into())), Verdict::Pass);
+        assert_eq!(
+            Verdict::new(Some("24"), &Ok(25.into())),
+            Verdict::Fail {
+                expected: "24".to_string(),
+                actual: "25".to_string()
+            }
+        );
+        assert_eq!(
+            Verdict::new(None, &Ok("CMZ".into())),
+            Verdict::Missing {
+                actual: "CMZ".to_string()
+            }
+        );
+        assert_eq!(Verdict::new(None, &Ok(Answer::Unsolved)), Verdict::Unsolved);
+        assert_eq!(
+            Verdict::new(Some("#.\n.#"), &Ok("#.\n.#\n".into())),
+            Verdict::Pass
+        );
+        assert!(Verdict::new(Some("1"), &Ok(Answer::Unsolved)).is_failure());
+    }
+
+    #[test]
+    fn recorded_answers_round_trip() {
+        let path = std::env::temp_dir().join("aoc-known-answers.json");
+        let mut answers = KnownAnswers::default();
+        answers.record(14, 2, "test", &93.into());
+        answers.record(14, 1, "test", &24.into());
+        answers.record(14, 1, "test", &25.into());
+        answers.save(&path).unwrap();
+        let loaded = KnownAnswers::load(&path);
+        fs::remove_file(path).unwrap();
+        let loaded = loaded.unwrap();
+        assert_eq!(loaded.get(14, 1, "test"), Some("25"));
+        assert_eq!(loaded.get(14, 2, "test"), Some("93"));
+        assert_eq!(loaded.get(14, 2, "prod"), None);
+    }
+}
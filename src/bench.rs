@@ -0,0 +1,284 @@
+use std::{fmt::Display, path::Path, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    days::Day,
+    solution::{Answer, Failure},
+    store::{self, StoreError},
+};
+
+/// A timed step of a day's solution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Step {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Step {
+    fn of_part(part: u8) -> Self {
+        match part {
+            1 => Step::Part1,
+            _ => Step::Part2,
+        }
+    }
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Step::Parse => "parse",
+            Step::Part1 => "part 1",
+            Step::Part2 => "part 2",
+        })
+    }
+}
+
+/// How long one step of a day took on one input over repeated runs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timing {
+    pub day: u8,
+    pub input: String,
+    pub step: Step,
+    pub runs: usize,
+    pub median_nanos: u64,
+    pub min_nanos: u64,
+}
+
+impl Timing {
+    fn new(day: u8, input: &str, step: Step, mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        Timing {
+            day,
+            input: input.to_string(),
+            step,
+            runs: samples.len(),
+            median_nanos: nanos(samples[samples.len() / 2]),
+            min_nanos: nanos(samples[0]),
+        }
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_nanos)
+    }
+}
+
+fn nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+/// What benchmarking a day on one input found: the timings of the steps
+/// that succeeded on every run, and how the others failed.
+#[derive(Debug)]
+pub struct Measurement {
+    pub timings: Vec<Timing>,
+    pub failures: Vec<(Step, Failure)>,
+}
+
+/// Runs `parts` of `day` on `input` `runs` times, timing the parse step and
+/// each solved part separately.
+///
+/// Unsolved parts are not timed. A part that fails is not run again and only
+/// its own timing is dropped; if parsing fails, nothing is timed.
+pub fn measure(day: &Day, input_name: &str, input: &str, parts: &[u8], runs: usize) -> Measurement {
+    let mut parts = parts.to_vec();
+    let mut failures = Vec::new();
+    let mut parse_samples = Vec::with_capacity(runs);
+    let mut part_samples: Vec<(u8, Vec<Duration>)> =
+        parts.iter().map(|&part| (part, Vec::new())).collect();
+    for _ in 0..runs {
+        let run = match (day.run)(input, &parts) {
+            Ok(run) => run,
+            Err(failure) => {
+                failures.push((Step::Parse, failure));
+                parse_samples.clear();
+                break;
+            }
+        };
+        parse_samples.push(run.parse_elapsed);
+        for part_run in run.parts {
+            let samples = part_samples
+                .iter_mut()
+                .find(|(part, _)| *part == part_run.part)
+                .map(|(_, samples)| samples)
+                .expect("only the parts asked for are run");
+            match part_run.answer {
+                Ok(Answer::Unsolved) => {}
+                Ok(_) => samples.push(part_run.elapsed),
+                Err(failure) => {
+                    samples.clear();
+                    parts.retain(|&part| part != part_run.part);
+                    failures.push((Step::of_part(part_run.part), failure));
+                }
+            }
+        }
+    }
+    let mut timings = Vec::new();
+    if !parse_samples.is_empty() {
+        timings.push(Timing::new(
+            day.number,
+            input_name,
+            Step::Parse,
+            parse_samples,
+        ));
+    }
+    timings.extend(
+        part_samples
+            .into_iter()
+            .filter(|(_, samples)| !samples.is_empty())
+            .map(|(part, samples)| {
+                Timing::new(day.number, input_name, Step::of_part(part), samples)
+            }),
+    );
+    Measurement { timings, failures }
+}
+
+/// Timings saved by an earlier benchmark run.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Baseline(Vec<Timing>);
+
+impl Baseline {
+    pub fn new(timings: Vec<Timing>) -> Self {
+        Baseline(timings)
+    }
+
+    /// Reads the baseline stored at `path`; a missing file holds no timings.
+    pub fn load(path: &Path) -> Result<Self, StoreError> {
+        Ok(Baseline(store::load(path)?.unwrap_or_default()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), StoreError> {
+        store::save(path, &self.0)
+    }
+
+    fn get(&self, timing: &Timing) -> Option<&Timing> {
+        self.0.iter().find(|baseline| {
+            baseline.day == timing.day
+                && baseline.input == timing.input
+                && baseline.step == timing.step
+        })
+    }
+
+    /// Compares `timing` with the baseline timing of the same step, if any.
+    pub fn compare(&self, timing: &Timing, threshold_percent: f64) -> Option<Change> {
+        let baseline = self.get(timing)?;
+        let percent = if baseline.median_nanos == 0 {
+            0.0
+        } else {
+            (timing.median_nanos as f64 / baseline.median_nanos as f64 - 1.0) * 100.0
+        };
+        Some(Change {
+            baseline: baseline.median(),
+            percent,
+            regressed: percent > threshold_percent,
+        })
+    }
+}
+
+/// How a median timing moved relative to the baseline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Change {
+    pub baseline: Duration,
+    /// Positive when the step got slower.
+    pub percent: f64,
+    pub regressed: bool,
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:+.1}%", self.percent)
+    }
+}
+
+/// Formats `duration` with a unit that keeps it short, e.g. `12.3ms`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    if nanos < 1_000.0 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000.0 {
+        format!("{:.1}µs", nanos / 1_000.0)
+    } else if nanos < 1_000_000_000.0 {
+        format!("{:.1}ms", nanos / 1_000_000.0)
+    } else {
+        format!("{:.2}s", nanos / 1_000_000_000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::DAYS;
+
+    fn timing(step: Step, median_nanos: u64) -> Timing {
+        Timing {
+            day: 6,
+            input: "test".to_string(),
+            step,
+            runs: 3,
+            median_nanos,
+            min_nanos: median_nanos,
+        }
+    }
+
+    #[test]
+    fn measure_times_parse_and_solved_parts() {
+        let steps = |measurement: &Measurement| {
+            let timings: Vec<Step> = measurement.timings.iter().map(|t| t.step).collect();
+            let failures: Vec<Step> = measurement.failures.iter().map(|(step, _)| *step).collect();
+            (timings, failures)
+        };
+        let day1 = &DAYS[0];
+        let measurement = measure(day1, "test", "1\n2\n\n3", &[1, 2], 3);
+        assert_eq!(
+            steps(&measurement),
+            (vec![Step::Parse, Step::Part1, Step::Part2], vec![])
+        );
+        assert!(measurement.timings.iter().all(|timing| timing.runs == 3));
+        let day19 = DAYS.iter().find(|day| day.number == 19).unwrap();
+        let blueprint = "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. \
+            Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.";
+        let measurement = measure(day19, "test", blueprint, &[2], 3);
+        assert_eq!(steps(&measurement), (vec![Step::Parse], vec![]));
+    }
+
+    #[test]
+    fn failing_steps_keep_the_other_timings() {
+        // The sensor covers the whole square, leaving nowhere for part 2.
+        let day15 = DAYS.iter().find(|day| day.number == 15).unwrap();
+        let sensor = "Sensor at x=10, y=10: closest beacon is at x=30, y=30";
+        let measurement = measure(day15, "test", sensor, &[1, 2], 3);
+        let steps: Vec<Step> = measurement.timings.iter().map(|t| t.step).collect();
+        assert_eq!(steps, [Step::Parse, Step::Part1]);
+        assert!(measurement.timings.iter().all(|timing| timing.runs == 3));
+        assert!(matches!(
+            measurement.failures.as_slice(),
+            [(Step::Part2, Failure::Panicked(_))]
+        ));
+        let measurement = measure(day15, "test", "Sensor", &[1, 2], 3);
+        assert!(measurement.timings.is_empty());
+        assert!(matches!(
+            measurement.failures.as_slice(),
+            [(Step::Parse, Failure::Parse(_))]
+        ));
+    }
+
+    #[test]
+    fn slowdowns_above_the_threshold_regress() {
+        let baseline = Baseline::new(vec![timing(Step::Part1, 1_000), timing(Step::Part2, 1_000)]);
+        let change = baseline.compare(&timing(Step::Part1, 1_050), 10.0).unwrap();
+        assert!(!change.regressed);
+        assert_eq!(change.to_string(), "+5.0%");
+        let change = baseline.compare(&timing(Step::Part2, 1_200), 10.0).unwrap();
+        assert!(change.regressed);
+        assert_eq!(baseline.compare(&timing(Step::Parse, 1_000), 10.0), None);
+    }
+
+    #[test]
+    fn durations_use_a_readable_unit() {
+        assert_eq!(format_duration(Duration::from_nanos(950)), "950ns");
+        assert_eq!(format_duration(Duration::from_micros(1_500)), "1.5ms");
+        assert_eq!(format_duration(Duration::from_secs(3)), "3.00s");
+    }
+}
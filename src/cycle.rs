@@ -0,0 +1,146 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// A quantity accumulated by a simulation that grows by the same amount over
+/// every period once the simulation repeats itself.
+pub trait Accumulated: Clone {
+    /// `self` plus `periods` times the growth from `before` to `after`.
+    fn extrapolate(&self, before: &Self, after: &Self, periods: u64) -> Self;
+}
+
+macro_rules! impl_accumulated {
+    ($($number:ty),+) => {
+        $(
+            impl Accumulated for $number {
+                fn extrapolate(&self, before: &Self, after: &Self, periods: u64) -> Self {
+                    self + (after - before) * periods as $number
+                }
+            }
+        )+
+    };
+}
+
+impl_accumulated!(i64, u64, usize);
+
+/// Extrapolates every element on its own.
+impl<T: Accumulated> Accumulated for Vec<T> {
+    fn extrapolate(&self, before: &Self, after: &Self, periods: u64) -> Self {
+        self.iter()
+            .zip(before.iter().zip(after))
+            .map(|(value, (before, after))| value.extrapolate(before, after, periods))
+            .collect()
+    }
+}
+
+/// Where a simulation starts repeating itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// The first step of the first period.
+    pub start: u64,
+    /// The number of steps in a period.
+    pub length: u64,
+}
+
+/// The value of `metric` after `steps` steps of the simulation in `state`,
+/// where `step` advances the simulation by one step.
+///
+/// After every step, `key` sums up the part of the state that determines
+/// the rest of the simulation. As soon as a key repeats, the simulation is
+/// periodic and the metric is extrapolated instead of simulating the
+/// remaining steps, so `steps` may be arbitrarily large.
+///
+/// Returns the metric together with the cycle, if one was found before
+/// reaching `steps`.
+pub fn extrapolate<S, K, M>(
+    state: &mut S,
+    steps: u64,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+    mut metric: impl FnMut(&S) -> M,
+) -> (M, Option<Cycle>)
+where
+    K: Eq + Hash,
+    M: Accumulated,
+{
+    let mut seen = HashMap::new();
+    let mut metrics = Vec::new();
+    for current in 0.. {
+        metrics.push(metric(state));
+        if current == steps {
+            break;
+        }
+        if let Some(start) = seen.insert(key(state), current) {
+            let cycle = Cycle {
+                start,
+                length: current - start,
+            };
+            let periods = (steps - start) / cycle.length;
+            let offset = (start + (steps - start) % cycle.length) as usize;
+            let value = metrics[offset].extrapolate(
+                &metrics[start as usize],
+                &metrics[current as usize],
+                periods,
+            );
+            return (value, Some(cycle));
+        }
+        step(state);
+    }
+    (metrics.pop().expect("the initial metric is recorded"), None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Walks 0, 1, 2, 3, 4, 5, 2, 3, 4, 5, ... summing up the positions.
+    fn walk(steps: u64) -> (u64, Option<Cycle>) {
+        extrapolate(
+            &mut (0u64, 0u64),
+            steps,
+            |(position, total)| {
+                *position = if *position == 5 { 2 } else { *position + 1 };
+                *total += *position;
+            },
+            |&(position, _)| position,
+            |&(_, total)| total,
+        )
+    }
+
+    #[test]
+    fn periodic_simulations_are_extrapolated() {
+        assert_eq!(walk(3), (1 + 2 + 3, None));
+        assert_eq!(
+            walk(9),
+            (
+                1 + 2 + 3 + 4 + 5 + 2 + 3 + 4 + 5,
+                Some(Cycle {
+                    start: 2,
+                    length: 4
+                })
+            )
+        );
+        assert_eq!(walk(1_000_003).0, 1 + 2 + 250_000 * 14 + 3);
+    }
+
+    #[test]
+    fn every_element_of_a_vector_is_extrapolated() {
+        let mut counters = vec![0u64, 0];
+        let (counts, cycle) = extrapolate(
+            &mut counters,
+            1_000,
+            |counters| {
+                counters[0] += 1;
+                counters[1] += 2;
+            },
+            |counters| counters[0] % 10,
+            Clone::clone,
+        );
+        assert_eq!(counts, [1_000, 2_000]);
+        assert_eq!(
+            cycle,
+            Some(Cycle {
+                start: 0,
+                length: 10
+            })
+        );
+    }
+}
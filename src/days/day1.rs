@@ -0,0 +1,531 @@
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    fmt::{Display, Write},
+    io::{self, BufRead, BufReader, Read},
+};
+
+use crate::{
+    generate::Rng,
+    parse::{Cursor, ParseError},
+    solution::{Answer, Solution},
+};
+
+/// What an elf carries: the calories of each of their items.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    /// The elf's place in the inventory, counting from 1 as the puzzle does.
+    pub index: usize,
+    pub items: Vec<u32>,
+    pub total: u64,
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input<'a> = Vec<Elf>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut inventory = Inventory::default();
+        let mut elves = Vec::new();
+        for line in input.split('\n') {
+            elves.extend(inventory.line(line)?);
+        }
+        elves.extend(inventory.end()?);
+        Ok(elves)
+    }
+
+    fn part1(elves: &Self::Input<'_>) -> Answer {
+        top_total(elves, 1).into()
+    }
+
+    fn part2(elves: &Self::Input<'_>) -> Answer {
+        top_total(elves, 3).into()
+    }
+}
+
+/// Reads an inventory a line at a time, so that parsing it whole and
+/// streaming it follow the same grammar: every elf carries at least one item,
+/// a single blank line separates elves, and lines end in `\n` or `\r\n`.
+/// Blank lines may only follow the last elf.
+#[derive(Debug, Default)]
+struct Inventory {
+    /// The lines read so far.
+    lines: usize,
+    elves: usize,
+    items: Vec<u32>,
+    /// The first blank line not ending an elf, which is only fine at the end.
+    blank: Option<usize>,
+}
+
+impl Inventory {
+    /// Reads the next line, without its `\n`, and returns the elf it ends.
+    fn line(&mut self, line: &str) -> Result<Option<Elf>, ParseError> {
+        self.lines += 1;
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        if line.is_empty() {
+            if self.items.is_empty() {
+                self.blank.get_or_insert(self.lines);
+                return Ok(None);
+            }
+            return Ok(Some(self.elf()));
+        }
+        if let Some(blank) = self.blank {
+            let expected = match self.elves {
+                0 => "an item before any blank line",
+                _ => "one blank line between elves",
+            };
+            return Err(ParseError {
+                line: blank,
+                column: 1,
+                expected: expected.to_string(),
+                found: "end of line".to_string(),
+            });
+        }
+        self.items.push(Inventory::item(self.lines, line)?);
+        Ok(None)
+    }
+
+    /// Ends the inventory and returns its last elf, failing if there is no
+    /// elf at all.
+    fn end(mut self) -> Result<Option<Elf>, ParseError> {
+        if !self.items.is_empty() {
+            return Ok(Some(self.elf()));
+        }
+        if self.elves == 0 {
+            return Err(ParseError {
+                line: 1,
+                column: 1,
+                expected: "at least one item".to_string(),
+                found: "end of input".to_string(),
+            });
+        }
+        Ok(None)
+    }
+
+    fn elf(&mut self) -> Elf {
+        self.elves += 1;
+        let items = std::mem::take(&mut self.items);
+        Elf {
+            index: self.elves,
+            total: items.iter().map(|&calories| calories as u64).sum(),
+            items,
+        }
+    }
+
+    /// The calories of the item on line `number`, with errors pointing at
+    /// that line of the inventory.
+    fn item(number: usize, line: &str) -> Result<u32, ParseError> {
+        let mut cursor = Cursor::new(line);
+        cursor
+            .number::<u32>()
+            .and_then(|calories| cursor.end().map(|_| calories))
+            .map_err(|error| ParseError {
+                line: number,
+                ..error
+            })
+    }
+}
+
+/// The `n` elves carrying the most calories, most first, followed by any
+/// other elves carrying as much as the last of them.
+pub fn top(elves: &[Elf], n: usize) -> Vec<&Elf> {
+    let mut ranked: Vec<&Elf> = elves.iter().collect();
+    ranked.sort_by_key(|elf| (Reverse(elf.total), elf.index));
+    let Some(last) = n.checked_sub(1).and_then(|last| ranked.get(last)) else {
+        ranked.truncate(n);
+        return ranked;
+    };
+    let least = last.total;
+    ranked.retain(|elf| elf.total >= least);
+    ranked
+}
+
+/// The calories carried by the `n` elves carrying the most, which ties do
+/// not change.
+fn top_total(elves: &[Elf], n: usize) -> u64 {
+    let mut leaders = Leaderboard::new(n);
+    for elf in elves {
+        leaders.push(elf.index, elf.total);
+    }
+    leaders.total(n)
+}
+
+/// The `n` elves carrying the most calories so far, kept in a heap that
+/// never holds more than `n` of them. Of elves carrying as much, the earlier
+/// ones are kept.
+#[derive(Debug, Clone)]
+pub struct Leaderboard {
+    n: usize,
+    /// Whose total and index come first is dropped first: the least total,
+    /// and the latest elf among equal totals.
+    heap: BinaryHeap<Reverse<(u64, Reverse<usize>)>>,
+}
+
+impl Leaderboard {
+    pub fn new(n: usize) -> Self {
+        Leaderboard {
+            n,
+            heap: BinaryHeap::with_capacity(n + 1),
+        }
+    }
+
+    pub fn push(&mut self, index: usize, total: u64) {
+        self.heap.push(Reverse((total, Reverse(index))));
+        if self.heap.len() > self.n {
+            self.heap.pop();
+        }
+    }
+
+    /// The index and total of the elves kept, most first.
+    pub fn ranked(&self) -> Vec<(usize, u64)> {
+        let mut ranked: Vec<(usize, u64)> = self
+            .heap
+            .iter()
+            .map(|&Reverse((total, Reverse(index)))| (index, total))
+            .collect();
+        ranked.sort_by_key(|&(index, total)| (Reverse(total), index));
+        ranked
+    }
+
+    /// The calories carried by the first `n` elves kept.
+    pub fn total(&self, n: usize) -> u64 {
+        self.ranked().iter().take(n).map(|&(_, total)| total).sum()
+    }
+}
+
+/// What reading an inventory as a stream keeps of it.
+#[derive(Debug, Clone)]
+pub struct Tally {
+    pub elves: usize,
+    pub calories: u64,
+    pub leaders: Leaderboard,
+}
+
+/// Reads the inventory from `reader` a line at a time, keeping only the
+/// `n` elves carrying the most (and at least the three both parts need), so
+/// that inventories of any size are read in constant space.
+///
+/// The inventory follows the same grammar as when it is parsed whole, and
+/// errors point at the line of the inventory they are on.
+pub fn stream(reader: impl Read, n: usize) -> Result<Tally, StreamError> {
+    let mut tally = Tally {
+        elves: 0,
+        calories: 0,
+        leaders: Leaderboard::new(n.max(3)),
+    };
+    let mut add = |elf: Elf| {
+        tally.elves += 1;
+        tally.calories += elf.total;
+        tally.leaders.push(elf.index, elf.total);
+    };
+    let mut reader = BufReader::new(reader);
+    let mut inventory = Inventory::default();
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if reader.read_line(&mut line).map_err(StreamError::Io)? == 0 {
+            break;
+        }
+        let line = line.strip_suffix('\n').unwrap_or(&line);
+        if let Some(elf) = inventory.line(line).map_err(StreamError::Parse)? {
+            add(elf);
+        }
+    }
+    if let Some(elf) = inventory.end().map_err(StreamError::Parse)? {
+        add(elf);
+    }
+    Ok(tally)
+}
+
+/// Why an inventory could not be streamed.
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "could not read the inventory: {}", err),
+            StreamError::Parse(err) => write!(f, "invalid input: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+/// A line of the report: a statistic, its value, and the elves it is about.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statistic {
+    pub name: String,
+    pub value: String,
+    pub elves: Vec<usize>,
+}
+
+impl Statistic {
+    fn new(name: impl Into<String>, value: impl ToString) -> Self {
+        Statistic {
+            name: name.into(),
+            value: value.to_string(),
+            elves: Vec::new(),
+        }
+    }
+
+    fn about(mut self, elves: impl IntoIterator<Item = usize>) -> Self {
+        self.elves = elves.into_iter().collect();
+        self
+    }
+}
+
+/// Reports both parts, the top `n` elves with their ties, and the mean,
+/// median and distribution of the totals over `buckets` ranges of calories.
+pub fn report(elves: &[Elf], n: usize, buckets: usize) -> Vec<Statistic> {
+    let mut statistics = vec![
+        Statistic::new("part 1", top_total(elves, 1)).about(indexes(&top(elves, 1))),
+        Statistic::new("part 2", top_total(elves, 3)).about(indexes(&top(elves, 3))),
+        Statistic::new(format!("top {}", n), top_total(elves, n)).about(indexes(&top(elves, n))),
+        Statistic::new("elves", elves.len()),
+        Statistic::new("calories", elves.iter().map(|elf| elf.total).sum::<u64>()),
+    ];
+    let mut totals: Vec<u64> = elves.iter().map(|elf| elf.total).collect();
+    totals.sort_unstable();
+    let (Some(&least), Some(&most)) = (totals.first(), totals.last()) else {
+        return statistics;
+    };
+    let mean = totals.iter().map(|&total| total as f64).sum::<f64>() / totals.len() as f64;
+    let middle = totals.len() / 2;
+    let median = match totals.len() % 2 {
+        0 => (totals[middle - 1] as f64 + totals[middle] as f64) / 2.0,
+        _ => totals[middle] as f64,
+    };
+    statistics.push(Statistic::new("mean", format!("{:.1}", mean)));
+    statistics.push(Statistic::new("median", median));
+    // Equal ranges from the least to the most calories, the last one
+    // including the most.
+    let buckets = buckets.max(1) as u64;
+    let width = (most - least) / buckets + 1;
+    for bucket in 0..buckets {
+        let from = least + bucket * width;
+        if from > most {
+            break;
+        }
+        let to = (from + width - 1).min(most);
+        let count = totals
+            .iter()
+            .filter(|&&total| (from..=to).contains(&total))
+            .count();
+        statistics.push(Statistic::new(format!("{}-{}", from, to), count));
+    }
+    statistics
+}
+
+/// Reports what a streamed inventory keeps: both parts, the top `n` elves
+/// without their ties, and the mean total.
+pub fn report_tally(tally: &Tally, n: usize) -> Vec<Statistic> {
+    let ranked = tally.leaders.ranked();
+    let leaders = |n: usize| ranked.iter().take(n).map(|&(index, _)| index);
+    let mut statistics = vec![
+        Statistic::new("part 1", tally.leaders.total(1)).about(leaders(1)),
+        Statistic::new("part 2", tally.leaders.total(3)).about(leaders(3)),
+        Statistic::new(format!("top {}", n), tally.leaders.total(n)).about(leaders(n)),
+        Statistic::new("elves", tally.elves),
+        Statistic::new("calories", tally.calories),
+    ];
+    if tally.elves > 0 {
+        let mean = tally.calories as f64 / tally.elves as f64;
+        statistics.push(Statistic::new("mean", format!("{:.1}", mean)));
+    }
+    statistics
+}
+
+/// The report as aligned columns.
+pub fn report_text(statistics: &[Statistic]) -> String {
+    let name_width = statistics.iter().map(|s| s.name.len()).max().unwrap_or(0);
+    let value_width = statistics.iter().map(|s| s.value.len()).max().unwrap_or(0);
+    let mut text = String::new();
+    for statistic in statistics {
+        let elves = match statistic.elves.as_slice() {
+            [] => String::new(),
+            [elf] => format!("  elf {}", elf),
+            elves => format!("  elves {}", join(elves, ", ")),
+        };
+        let line = format!(
+            "{:name_width$}  {:>value_width$}{}",
+            statistic.name, statistic.value, elves
+        );
+        writeln!(text, "{}", line.trim_end()).expect("writing to a string never fails");
+    }
+    text
+}
+
+/// The report as CSV, the elves of a statistic separated by spaces.
+pub fn report_csv(statistics: &[Statistic]) -> String {
+    let mut csv = "statistic,value,elves\n".to_string();
+    for statistic in statistics {
+        writeln!(
+            csv,
+            "{},{},{}",
+            statistic.name,
+            statistic.value,
+            join(&statistic.elves, " ")
+        )
+        .expect("writing to a string never fails");
+    }
+    csv
+}
+
+fn indexes(elves: &[&Elf]) -> Vec<usize> {
+    elves.iter().map(|elf| elf.index).collect()
+}
+
+fn join(elves: &[usize], separator: &str) -> String {
+    elves
+        .iter()
+        .map(usize::to_string)
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Generates the inventories of `size` elves, each carrying 1 to 15 snacks.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            (0..rng.range(1..=15))
+                .map(|_| rng.range(1_000..=60_000).to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixture::Fixture;
+
+    fn example() -> Vec<Elf> {
+        let fixture = Fixture::parse(include_str!("../../fixtures/day1/example.txt")).unwrap();
+        Day1::parse(&fixture.input).unwrap()
+    }
+
+    #[test]
+    fn elves_keep_their_items() {
+        let elves = example();
+        assert_eq!(elves.len(), 5);
+        assert_eq!(
+            elves[3],
+            Elf {
+                index: 4,
+                items: vec![7000, 8000, 9000],
+                total: 24000
+            }
+        );
+        let error = Day1::parse("1000\n2000\n\nlots").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+    }
+
+    #[test]
+    fn ties_join_the_top() {
+        let mut elves = example();
+        assert_eq!(indexes(&top(&elves, 3)), [4, 3, 5]);
+        elves[0].total = 10000;
+        assert_eq!(indexes(&top(&elves, 3)), [4, 3, 1, 5]);
+        assert_eq!(top_total(&elves, 3), 45000);
+        assert_eq!(indexes(&top(&elves, 9)), [4, 3, 1, 5, 2]);
+        assert!(top(&elves, 0).is_empty());
+    }
+
+    #[test]
+    fn report_has_both_parts_and_statistics() {
+        let statistics = report(&example(), 2, 2);
+        let csv = report_csv(&statistics);
+        assert_eq!(
+            csv,
+            "statistic,value,elves\n\
+             part 1,24000,4\n\
+             part 2,45000,4 3 5\n\
+             top 2,35000,4 3\n\
+             elves,5,\n\
+             calories,55000,\n\
+             mean,11000.0,\n\
+             median,10000,\n\
+             4000-14000,4,\n\
+             14001-24000,1,\n"
+        );
+        let text = report_text(&statistics);
+        assert!(text.starts_with("part 1         24000  elf 4\n"));
+        assert!(text.contains("\nmean         11000.0\n"));
+    }
+
+    #[test]
+    fn streaming_keeps_only_the_leaders() {
+        let fixture = Fixture::parse(include_str!("../../fixtures/day1/example.txt")).unwrap();
+        let tally = stream(fixture.input.as_bytes(), 1).unwrap();
+        assert_eq!((tally.elves, tally.calories), (5, 55000));
+        assert_eq!(tally.leaders.ranked(), [(4, 24000), (3, 11000), (5, 10000)]);
+        assert_eq!(
+            report_tally(&tally, 2)[..3],
+            report(&example(), 2, 1)[..3],
+            "both parts and the top elves match the report"
+        );
+
+        let mut leaders = Leaderboard::new(2);
+        for (index, total) in [(1, 5), (2, 7), (3, 5), (4, 7), (5, 9)] {
+            leaders.push(index, total);
+        }
+        assert_eq!(leaders.ranked(), [(5, 9), (2, 7)]);
+
+        let error = stream("1000\n\n2000\n3000 \n".as_bytes(), 3).unwrap_err();
+        assert!(matches!(
+            error,
+            StreamError::Parse(ParseError {
+                line: 4,
+                column: 5,
+                ..
+            })
+        ));
+        let tally = stream("1000\n\n2000\n\n".as_bytes(), 3).unwrap();
+        assert_eq!(tally.leaders.ranked(), [(2, 2000), (1, 1000)]);
+    }
+
+    #[test]
+    fn streaming_follows_the_parsed_grammar() {
+        let read = |input: &str| {
+            let streamed = stream(input.as_bytes(), 3).map_err(|error| match error {
+                StreamError::Parse(error) => error,
+                StreamError::Io(error) => panic!("{}", error),
+            });
+            let parsed = Day1::parse(input);
+            assert_eq!(
+                streamed.as_ref().map(|tally| tally.leaders.ranked()),
+                parsed.as_ref().map(|elves| {
+                    let mut leaders = Leaderboard::new(3);
+                    for elf in elves {
+                        leaders.push(elf.index, elf.total);
+                    }
+                    leaders.ranked()
+                }),
+                "{:?}",
+                input
+            );
+            parsed.map(|elves| elves.len())
+        };
+        assert_eq!(read("1000\n2000\n\n3000"), Ok(2));
+        assert_eq!(read("4000000000\n4000000000"), Ok(1));
+        assert_eq!(read("1000\r\n\r\n2000\r\n"), Ok(2));
+        let errors = [
+            ("", (1, 1), "at least one item"),
+            ("\n1000", (1, 1), "an item before any blank line"),
+            ("1000\n\n\n2000", (3, 1), "one blank line between elves"),
+            ("1000\n\nlots", (3, 1), "a number"),
+        ];
+        for (input, position, expected) in errors {
+            let error = read(input).unwrap_err();
+            assert_eq!((error.line, error.column), position, "{:?}", input);
+            assert_eq!(error.expected, expected, "{:?}", input);
+        }
+    }
+}
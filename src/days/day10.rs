@@ -1,20 +1,11 @@
-fn main() {
-    let test_input = include_str!("../input/day10/test.txt");
-    let prod_input = include_str!("../input/day10/prod.txt");
-
-    let test_part1_result = part1(test_input);
-    println!("test part1: {}", test_part1_result);
-    let prod_part1_result = part1(prod_input);
-    println!("prod part1: {}", prod_part1_result);
-
-    let test_part2_result = part2(test_input);
-    println!("test part2:\n{}", test_part2_result);
-    let prod_part2_result = part2(prod_input);
-    println!("prod part2:\n{}", prod_part2_result);
-}
-
-#[derive(Clone, Copy)]
-enum Instruction {
+use crate::{
+    generate::Rng,
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
     Noop,
     Addx(i32),
 }
@@ -65,7 +56,7 @@ impl Program {
         } else {
             self.screen.push('.');
         }
-        if self.cycle % 40 == 0 {
+        if self.cycle.is_multiple_of(40) {
             self.screen.push('\n');
         }
     }
@@ -75,10 +66,28 @@ impl Program {
     }
 }
 
-fn part1(input: &str) -> i32 {
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_instructions(input)
+    }
+
+    fn part1(instructions: &Self::Input<'_>) -> Answer {
+        signal_strength(instructions).into()
+    }
+
+    fn part2(instructions: &Self::Input<'_>) -> Answer {
+        render_screen(instructions).into()
+    }
+}
+
+fn signal_strength(instructions: &[Instruction]) -> i32 {
     let mut program = Program::new();
 
-    let mut instructions = parse_instructions(input);
+    let mut instructions = instructions.iter().copied();
 
     let mut signal_strength = 0;
     let mut next_instruction = instructions.next();
@@ -100,9 +109,9 @@ fn part1(input: &str) -> i32 {
     signal_strength
 }
 
-fn part2(input: &str) -> String {
+fn render_screen(instructions: &[Instruction]) -> String {
     let mut program = Program::new();
-    let mut instructions = parse_instructions(input);
+    let mut instructions = instructions.iter().copied();
     let mut next_instruction = instructions.next();
     while program.clock() <= 240 {
         program.draw();
@@ -119,17 +128,45 @@ fn part2(input: &str) -> String {
     program.screen
 }
 
-fn parse_instructions(input: &str) -> impl Iterator<Item = Instruction> + '_ {
-    input.lines().map(|line| match line {
-        "noop" => Instruction::Noop,
-        addx_instruction_str => {
-            let (_, x_num) = addx_instruction_str
-                .split_once(' ')
-                .expect("addx instruction should have space char");
-            let x = x_num
-                .parse::<i32>()
-                .expect("addx instruction should have number");
-            Instruction::Addx(x)
+fn parse_instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse::lines(input, |line| {
+        if line.eat("noop") {
+            Ok(Instruction::Noop)
+        } else if line.eat("addx ") {
+            Ok(Instruction::Addx(line.number()?))
+        } else {
+            Err(line.error("\"noop\" or \"addx\""))
         }
     })
 }
+
+/// Generates a program of `size` instructions, or more until it runs for the
+/// 240 cycles the screen takes. The register stays within the screen.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let (mut x, mut cycles) = (1, 0);
+    let mut instructions = Vec::new();
+    while instructions.len() < size || cycles < 240 {
+        let value = rng.range((-1 - x).max(-20)..=(40 - x).min(20));
+        if value == 0 || rng.chance(0.3) {
+            instructions.push("noop".to_string());
+            cycles += 1;
+        } else {
+            instructions.push(format!("addx {}", value));
+            x += value;
+            cycles += 2;
+        }
+    }
+    instructions.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_instructions_are_rejected() {
+        let error = parse_instructions("noop\naddx 3\nsubx 2").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.found, "\"subx\"");
+    }
+}
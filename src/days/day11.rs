@@ -0,0 +1,453 @@
+use crate::{
+    cycle,
+    generate::Rng,
+    parse::{Cursor, ParseError},
+    solution::{Answer, Solution},
+};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Operator {
+    Add,
+    Multiply,
+}
+
+#[derive(Clone, Copy)]
+enum OperationItem {
+    ItemLevel,
+    Literal(u64),
+}
+
+impl OperationItem {
+    fn parse(cursor: &mut Cursor) -> Result<Self, ParseError> {
+        if cursor.eat("old") {
+            return Ok(OperationItem::ItemLevel);
+        }
+        cursor
+            .number()
+            .map(OperationItem::Literal)
+            .map_err(|_| cursor.error("\"old\" or a number"))
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Operation {
+    lhs: OperationItem,
+    operator: Operator,
+    rhs: OperationItem,
+}
+
+fn apply_operation_on_item(item: &mut Item, operation: Operation) {
+    let lhs = match operation.lhs {
+        OperationItem::ItemLevel => item.0,
+        OperationItem::Literal(literal) => literal,
+    };
+    let rhs = match operation.rhs {
+        OperationItem::ItemLevel => item.0,
+        OperationItem::Literal(literal) => literal,
+    };
+    match operation.operator {
+        Operator::Add => item.0 = lhs + rhs,
+        Operator::Multiply => item.0 = lhs * rhs,
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Item(u64);
+
+struct InspectionResult {
+    new_monkey_index: usize,
+    item: Item,
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+    items: Vec<Item>,
+    operation: Operation,
+    test_divisible_by: u64,
+    destination_monkey_indexes: (usize, usize),
+    inspections_count: u64,
+}
+
+impl Monkey {
+    /// Inspects `item` and returns the index of the monkey it is thrown to.
+    fn inspect<F: FnMut(&mut Item)>(&self, item: &mut Item, mut after_inspection: F) -> usize {
+        apply_operation_on_item(item, self.operation);
+        after_inspection(item);
+        match item.0 % self.test_divisible_by {
+            0 => self.destination_monkey_indexes.1,
+            _ => self.destination_monkey_indexes.0,
+        }
+    }
+
+    fn inspect_items<F: FnMut(&mut Item)>(
+        &mut self,
+        mut after_inspection: F,
+    ) -> Vec<InspectionResult> {
+        let mut items = std::mem::take(&mut self.items);
+        let result: Vec<InspectionResult> = items
+            .iter_mut()
+            .map(|item| InspectionResult {
+                new_monkey_index: self.inspect(item, &mut after_inspection),
+                item: *item,
+            })
+            .collect();
+
+        self.inspections_count += result.len() as u64;
+
+        result
+    }
+}
+
+/// One item on its way between the monkeys, with how many times each monkey
+/// inspected it so far.
+#[derive(Clone)]
+struct TrackedItem {
+    item: Item,
+    monkey_index: usize,
+    inspections: Vec<u64>,
+}
+
+impl TrackedItem {
+    /// Plays a round for this item alone. The monkeys take their turns in
+    /// order, so an item thrown to a later monkey is inspected again in the
+    /// same round.
+    fn play_round(&mut self, monkeys: &[Monkey], lcm: u64) {
+        loop {
+            let monkey_index = self.monkey_index;
+            self.inspections[monkey_index] += 1;
+            self.monkey_index = monkeys[monkey_index].inspect(&mut self.item, |item| item.0 %= lcm);
+            if self.monkey_index <= monkey_index {
+                return;
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct MonkeyBuilder {
+    items: Vec<Item>,
+    operation: Option<Operation>,
+    test_divisible_by: Option<u64>,
+    destination_monkey_index_if_true: Option<usize>,
+    destination_monkey_index_if_false: Option<usize>,
+}
+
+impl MonkeyBuilder {
+    fn new() -> Self {
+        MonkeyBuilder {
+            items: Vec::new(),
+            ..Default::default()
+        }
+    }
+    fn add_item(&mut self, item: Item) -> &mut Self {
+        self.items.push(item);
+        self
+    }
+
+    fn set_operation(&mut self, operation: Operation) -> &mut Self {
+        self.operation = Some(operation);
+        self
+    }
+
+    fn set_test_divisible_by(&mut self, test_divisible_by: u64) -> &mut Self {
+        self.test_divisible_by = Some(test_divisible_by);
+        self
+    }
+
+    fn set_destination_monkey_index_if_true(
+        &mut self,
+        destination_monkey_index: usize,
+    ) -> &mut Self {
+        self.destination_monkey_index_if_true = Some(destination_monkey_index);
+        self
+    }
+    fn set_destination_monkey_index_if_false(
+        &mut self,
+        destination_monkey_index: usize,
+    ) -> &mut Self {
+        self.destination_monkey_index_if_false = Some(destination_monkey_index);
+        self
+    }
+
+    fn build(self) -> Result<Monkey, &'static str> {
+        Ok(Monkey {
+            items: self.items,
+            operation: self.operation.ok_or("\"Operation:\"")?,
+            test_divisible_by: self.test_divisible_by.ok_or("\"Test:\"")?,
+            destination_monkey_indexes: (
+                self.destination_monkey_index_if_false
+                    .ok_or("\"If false:\"")?,
+                self.destination_monkey_index_if_true
+                    .ok_or("\"If true:\"")?,
+            ),
+            inspections_count: 0,
+        })
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    // Along with each monkey, where the numbers of the monkeys it throws to
+    // start.
+    let (monkeys, targets): (Vec<_>, Vec<_>) = Cursor::new(input)
+        .sections()
+        .map(|monkey_details| {
+            let mut lines = monkey_details.lines();
+            let mut header = lines
+                .next()
+                .ok_or_else(|| monkey_details.error("\"Monkey \""))?;
+            header.tag("Monkey ")?;
+            header.number::<usize>()?;
+            header.tag(":")?;
+            header.end()?;
+            let mut monkey_builder = MonkeyBuilder::new();
+            let mut targets = Vec::new();
+            for mut detail_line in lines {
+                detail_line.take_while(|c| c == ' ');
+                if detail_line.eat("Starting items: ") {
+                    loop {
+                        monkey_builder.add_item(Item(detail_line.number()?));
+                        if !detail_line.eat(", ") {
+                            break;
+                        }
+                    }
+                } else if detail_line.eat("Operation: new = ") {
+                    let lhs = OperationItem::parse(&mut detail_line)?;
+                    detail_line.tag(" ")?;
+                    let operator =
+                        detail_line.one_of(&[('+', Operator::Add), ('*', Operator::Multiply)])?;
+                    detail_line.tag(" ")?;
+                    let rhs = OperationItem::parse(&mut detail_line)?;
+                    monkey_builder.set_operation(Operation { lhs, operator, rhs });
+                } else if detail_line.eat("Test: divisible by ") {
+                    let at = detail_line;
+                    match detail_line.number()? {
+                        0 => return Err(at.error("a positive divisor")),
+                        divisor => monkey_builder.set_test_divisible_by(divisor),
+                    };
+                } else if detail_line.eat("If true: throw to monkey ") {
+                    targets.push(detail_line);
+                    monkey_builder.set_destination_monkey_index_if_true(detail_line.number()?);
+                } else if detail_line.eat("If false: throw to monkey ") {
+                    targets.push(detail_line);
+                    monkey_builder.set_destination_monkey_index_if_false(detail_line.number()?);
+                } else {
+                    return Err(detail_line.error("a monkey attribute"));
+                }
+                detail_line.end()?;
+            }
+            let rest = monkey_details.rest();
+            let monkey = monkey_builder
+                .build()
+                .map_err(|expected| ParseError::at(input, &rest[rest.len()..], expected))?;
+            Ok((monkey, targets))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?
+        .into_iter()
+        .unzip();
+    for mut target in targets.into_iter().flatten() {
+        let at = target;
+        if target.number::<usize>()? >= monkeys.len() {
+            return Err(at.error("the number of a listed monkey"));
+        }
+    }
+    Ok(monkeys)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(monkeys: &Self::Input<'_>) -> Answer {
+        let mut monkeys = monkeys.clone();
+
+        let rounds = 20;
+        for _ in 0..rounds {
+            for i in 0..monkeys.len() {
+                let inspection_results = monkeys[i].inspect_items(|item| item.0 /= 3);
+                for inspection_result in inspection_results {
+                    monkeys[inspection_result.new_monkey_index]
+                        .items
+                        .push(inspection_result.item);
+                }
+            }
+        }
+
+        monkeys.sort_by_key(|monkey| std::cmp::Reverse(monkey.inspections_count));
+
+        monkeys
+            .iter()
+            .take(2)
+            .map(|monkey| monkey.inspections_count)
+            .product::<u64>()
+            .into()
+    }
+
+    fn part2(monkeys: &Self::Input<'_>) -> Answer {
+        let mut monkeys = monkeys.clone();
+
+        let lcm = monkeys
+            .iter()
+            .map(|monkey| monkey.test_divisible_by)
+            .reduce(|a, b| a * b)
+            .expect("at least one monkey");
+
+        // Items never interact, so each one is followed on its own. An item
+        // always comes back to the same monkey with the same worry level
+        // sooner or later, after which its inspections repeat every cycle.
+        let rounds = 10_000;
+        for monkey_index in 0..monkeys.len() {
+            for item in std::mem::take(&mut monkeys[monkey_index].items) {
+                let mut tracked = TrackedItem {
+                    item,
+                    monkey_index,
+                    inspections: vec![0; monkeys.len()],
+                };
+                let (inspections, _) = cycle::extrapolate(
+                    &mut tracked,
+                    rounds,
+                    |tracked| tracked.play_round(&monkeys, lcm),
+                    |tracked| (tracked.item.0, tracked.monkey_index),
+                    |tracked| tracked.inspections.clone(),
+                );
+                for (monkey, count) in monkeys.iter_mut().zip(inspections) {
+                    monkey.inspections_count += count;
+                }
+            }
+        }
+
+        monkeys.sort_by_key(|monkey| std::cmp::Reverse(monkey.inspections_count));
+
+        monkeys
+            .iter()
+            .take(2)
+            .map(|monkey| monkey.inspections_count)
+            .product::<u64>()
+            .into()
+    }
+}
+
+/// Generates the notes on `size` monkeys, between 2 and 9. As in the puzzle,
+/// one monkey squares the worry level if there are at least four, a few
+/// multiply it and the rest add to it, and the tests divide by distinct
+/// primes, whose product keeps squared worry levels within 64 bits in part 2.
+/// Notes whose worry levels would outgrow 64 bits during part 1 are drawn
+/// again.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let count = size.clamp(2, 9);
+    loop {
+        let mut divisors = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+        rng.shuffle(&mut divisors);
+        let squaring = (count >= 4).then(|| rng.below(count));
+        let other_monkey =
+            |rng: &mut Rng, monkey: usize| (monkey + 1 + rng.below(count - 1)) % count;
+        let notes: Vec<String> = (0..count)
+            .map(|monkey| {
+                let items: Vec<String> = (0..rng.range(1..=6))
+                    .map(|_| rng.range(50..=99).to_string())
+                    .collect();
+                let operation = if Some(monkey) == squaring {
+                    "old * old".to_string()
+                } else if rng.chance(0.25) {
+                    format!("old * {}", rng.range(2..=19))
+                } else {
+                    format!("old + {}", rng.range(1..=8))
+                };
+                let if_true = other_monkey(rng, monkey);
+                let if_false = loop {
+                    let if_false = other_monkey(rng, monkey);
+                    if if_false != if_true || count == 2 {
+                        break if_false;
+                    }
+                };
+                format!(
+                    "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}",
+                    monkey,
+                    items.join(", "),
+                    operation,
+                    divisors[monkey],
+                    if_true,
+                    if_false
+                )
+            })
+            .collect();
+        let notes = notes.join("\n\n");
+        let monkeys = parse_input(&notes).expect("generated notes are valid");
+        if !overflows_in_part1(&monkeys) {
+            return notes;
+        }
+    }
+}
+
+/// Whether some worry level outgrows 64 bits during the rounds of part 1.
+fn overflows_in_part1(monkeys: &[Monkey]) -> bool {
+    let operand = |item, worry| match item {
+        OperationItem::ItemLevel => worry,
+        OperationItem::Literal(literal) => literal,
+    };
+    let mut items: Vec<Vec<u64>> = monkeys
+        .iter()
+        .map(|monkey| monkey.items.iter().map(|item| item.0).collect())
+        .collect();
+    for _ in 0..20 {
+        for (index, monkey) in monkeys.iter().enumerate() {
+            for worry in std::mem::take(&mut items[index]) {
+                let Operation { lhs, operator, rhs } = monkey.operation;
+                let (lhs, rhs) = (operand(lhs, worry), operand(rhs, worry));
+                let worry = match operator {
+                    Operator::Add => lhs.checked_add(rhs),
+                    Operator::Multiply => lhs.checked_mul(rhs),
+                };
+                let Some(worry) = worry.map(|worry| worry / 3) else {
+                    return true;
+                };
+                let (if_false, if_true) = monkey.destination_monkey_indexes;
+                match worry % monkey.test_divisible_by {
+                    0 => items[if_true].push(worry),
+                    _ => items[if_false].push(worry),
+                }
+            }
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notes(divisor: u64, if_true: usize) -> String {
+        format!(
+            "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by {}
+    If true: throw to monkey {}
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items: 54
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 0
+    If false: throw to monkey 0",
+            divisor, if_true
+        )
+    }
+
+    #[test]
+    fn notes_are_checked() {
+        assert!(Day11::parse(&notes(23, 1)).is_ok());
+        assert_eq!(Day11::parse("").err().unwrap().expected, "\"Monkey \"");
+        let error = Day11::parse(&notes(23, 3)).err().unwrap();
+        assert_eq!((error.line, error.column), (5, 30));
+        assert_eq!(error.expected, "the number of a listed monkey");
+        let error = Day11::parse(&notes(0, 1)).err().unwrap();
+        assert_eq!((error.line, error.column), (4, 22));
+        assert_eq!(error.expected, "a positive divisor");
+    }
+}
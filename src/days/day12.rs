@@ -0,0 +1,146 @@
+use crate::{
+    generate::Rng,
+    grid::{Grid, Position},
+    parse::{Cursor, ParseError},
+    search,
+    solution::{Answer, Solution},
+};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Heightmap;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> Answer {
+        let (start, end) = grid.find_start_and_end();
+        grid.find_shortest_path(start, |position| position == end)
+            .expect("no path found")
+            .into()
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> Answer {
+        // Walking down from the end finds the nearest of all the lowest cells
+        // in a single search.
+        let (_, end) = grid.find_start_and_end();
+        grid.find_shortest_descent(end, |position| grid.0[position].0 == 0)
+            .expect("no path found")
+            .into()
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub enum CellType {
+    Start,
+    End,
+}
+
+#[derive(Clone)]
+pub struct Cell(u8, Option<CellType>);
+
+pub struct Heightmap(Grid<Cell>);
+
+impl Heightmap {
+    fn find_start_and_end(&self) -> (Position, Position) {
+        let find = |cell_type| {
+            self.0
+                .find(|cell| cell.1.as_ref() == Some(&cell_type))
+                .expect("start and end are checked when parsing")
+        };
+        (find(CellType::Start), find(CellType::End))
+    }
+
+    /// The fewest steps from `start` to a goal, climbing at most one height
+    /// unit per step.
+    fn find_shortest_path(
+        &self,
+        start: Position,
+        is_goal: impl Fn(Position) -> bool,
+    ) -> Option<u64> {
+        self.search(start, |from, to| to <= from + 1, is_goal)
+    }
+
+    /// The fewest steps from `start` to a goal when walking the other way,
+    /// descending at most one height unit per step.
+    fn find_shortest_descent(
+        &self,
+        start: Position,
+        is_goal: impl Fn(Position) -> bool,
+    ) -> Option<u64> {
+        self.search(start, |from, to| from <= to + 1, is_goal)
+    }
+
+    fn search(
+        &self,
+        start: Position,
+        can_step: impl Fn(u8, u8) -> bool,
+        is_goal: impl Fn(Position) -> bool,
+    ) -> Option<u64> {
+        let grid = &self.0;
+        let path = search::bfs(
+            start,
+            |&position| {
+                let height = grid[position].0;
+                grid.neighbours4(position)
+                    .filter(|&neighbour| can_step(height, grid[neighbour].0))
+                    .collect::<Vec<_>>()
+            },
+            |&position| is_goal(position),
+        )?;
+        Some(path.cost)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Heightmap, ParseError> {
+    let grid = Grid::parse(Cursor::new(input), |cursor| {
+        Ok(
+            match cursor.satisfy("a height between 'a' and 'z', 'S' or 'E'", |c| {
+                c.is_ascii_lowercase() || c == 'S' || c == 'E'
+            })? {
+                'S' => Cell(0, Some(CellType::Start)),
+                'E' => Cell(b'z' - b'a', Some(CellType::End)),
+                other => Cell(other as u8 - b'a', None),
+            },
+        )
+    })?;
+    for (cell_type, expected) in [
+        (CellType::Start, "a start 'S'"),
+        (CellType::End, "an end 'E'"),
+    ] {
+        if grid
+            .find(|cell| cell.1.as_ref() == Some(&cell_type))
+            .is_none()
+        {
+            return Err(ParseError::at(input, &input[input.len()..], expected));
+        }
+    }
+    Ok(Heightmap(grid))
+}
+
+/// Generates a heightmap `size` squares wide, at least 26, and a quarter as
+/// high. One row climbs steadily from the start on its left to the end on
+/// its right, so the end can always be reached.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let width = size.max(26);
+    let height = (width / 4).max(2);
+    let climb = |x: usize| (x * 25 / (width - 1)) as i64;
+    let path_row = rng.below(height);
+    (0..height)
+        .map(|y| {
+            (0..width)
+                .map(|x| match (y == path_row, x) {
+                    (true, 0) => 'S',
+                    (true, x) if x == width - 1 => 'E',
+                    (true, x) => (b'a' + climb(x) as u8) as char,
+                    (false, x) => {
+                        (b'a' + (climb(x) + rng.range(-3..=3)).clamp(0, 25) as u8) as char
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
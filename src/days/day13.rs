@@ -0,0 +1,177 @@
+use crate::{
+    generate::Rng,
+    parse::{Cursor, ParseError},
+    solution::{Answer, Solution},
+};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Vec<Pair>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(pairs: &Self::Input<'_>) -> Answer {
+        pairs
+            .iter()
+            .enumerate()
+            .filter_map(|(index, pair)| {
+                if pair.is_in_right_order() {
+                    Some((index + 1) as u32)
+                } else {
+                    None
+                }
+            })
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part2(pairs: &Self::Input<'_>) -> Answer {
+        let mut packets: Vec<Packet> = pairs
+            .iter()
+            .flat_map(|pair| [pair.left.clone(), pair.right.clone()])
+            .collect();
+        let divider2 = Packet::List(vec![Packet::List(vec![Packet::Number(2)])]);
+        packets.push(divider2.clone());
+        let divider6 = Packet::List(vec![Packet::List(vec![Packet::Number(6)])]);
+        packets.push(divider6.clone());
+
+        packets.sort();
+
+        packets
+            .iter()
+            .enumerate()
+            .filter_map(|(index, packet)| {
+                if packet == &divider2 || packet == &divider6 {
+                    Some((index + 1) as u32)
+                } else {
+                    None
+                }
+            })
+            .product::<u32>()
+            .into()
+    }
+}
+
+#[derive(Debug, Eq, Clone)]
+pub enum Packet {
+    Number(u32),
+    List(Vec<Packet>),
+}
+
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => a == b,
+            (Self::List(a), Self::List(b)) => a == b,
+            (Self::Number(a), Self::List(b)) => &vec![Self::Number(*a)] == b,
+            (Self::List(a), Self::Number(b)) => a == &vec![Self::Number(*b)],
+        }
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Packet::Number(a), Packet::Number(b)) => a.cmp(b),
+            (Packet::List(a), Packet::List(b)) => a.cmp(b),
+            (Packet::Number(a), Packet::List(b)) => vec![Packet::Number(*a)].cmp(b),
+            (Packet::List(a), Packet::Number(b)) => a.cmp(&vec![Packet::Number(*b)]),
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug)]
+pub struct Pair {
+    left: Packet,
+    right: Packet,
+}
+
+impl Pair {
+    fn is_in_right_order(&self) -> bool {
+        match (&self.left, &self.right) {
+            (Packet::List(a), Packet::List(b)) => a < b,
+            _ => panic!("Pair should be constructed from two lists"),
+        }
+    }
+}
+
+fn parse_packet(cursor: &mut Cursor) -> Result<Packet, ParseError> {
+    if !cursor.eat("[") {
+        return cursor
+            .number()
+            .map(Packet::Number)
+            .map_err(|_| cursor.error("\"[\" or a number"));
+    }
+    let mut list = Vec::new();
+    if cursor.eat("]") {
+        return Ok(Packet::List(list));
+    }
+    loop {
+        list.push(parse_packet(cursor)?);
+        if cursor.eat("]") {
+            return Ok(Packet::List(list));
+        }
+        if !cursor.eat(",") {
+            return Err(cursor.error("\",\" or \"]\""));
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Pair>, ParseError> {
+    Cursor::new(input)
+        .sections()
+        .map(|pair| {
+            let mut packets = pair.parse_lines(|line| {
+                if line.peek() != Some('[') {
+                    return Err(line.error("\"[\""));
+                }
+                parse_packet(line)
+            })?;
+            if packets.len() != 2 {
+                return Err(pair.error("a pair of packets on two lines"));
+            }
+            let right = packets.pop().expect("two packets");
+            let left = packets.pop().expect("two packets");
+            Ok(Pair { left, right })
+        })
+        .collect()
+}
+
+/// Generates `size` pairs of packets, nested up to four lists deep. As in the
+/// puzzle, none of them compares equal to a divider packet.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let dividers = [Packet::Number(2), Packet::Number(6)];
+    let packet = |rng: &mut Rng| loop {
+        let packet = generate_packet(rng, 4);
+        let parsed = parse_packet(&mut Cursor::new(&packet)).expect("generated packets parse");
+        if !dividers.contains(&parsed) {
+            break packet;
+        }
+    };
+    (0..size.max(1))
+        .map(|_| format!("{}\n{}", packet(rng), packet(rng)))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn generate_packet(rng: &mut Rng, depth: usize) -> String {
+    let values: Vec<String> = (0..rng.below(5))
+        .map(|_| {
+            if depth > 0 && rng.chance(0.3) {
+                generate_packet(rng, depth - 1)
+            } else {
+                rng.below(11).to_string()
+            }
+        })
+        .collect();
+    format!("[{}]", values.join(","))
+}
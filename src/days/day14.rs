@@ -0,0 +1,302 @@
+use std::fmt::Display;
+
+use crate::{
+    generate::Rng,
+    geometry::Point2,
+    grid::Grid,
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+    visualize::{Frame, Palette, Rgb, Simulation},
+};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = Map;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Map::try_from(input)
+    }
+
+    fn part1(map: &Self::Input<'_>) -> Answer {
+        let mut map = map.clone();
+        loop {
+            if !map.drop_sand_unit(false) {
+                break;
+            }
+        }
+        map.get_number_of_sand_at_rest().into()
+    }
+
+    fn part2(map: &Self::Input<'_>) -> Answer {
+        let mut map = map.clone();
+        for _ in 0.. {
+            if !map.drop_sand_unit(true) {
+                break;
+            }
+        }
+        map.get_number_of_sand_at_rest().into()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl Tile {
+    fn symbol(self) -> char {
+        match self {
+            Tile::Air => '.',
+            Tile::Rock => '#',
+            Tile::Sand => 'o',
+        }
+    }
+}
+
+const SAND_SOURCE: (usize, usize) = (500, 0);
+
+/// The largest coordinates of rocks, which keep the cave small and the sand
+/// piling up around the source clear of its left edge.
+const MAX_X: usize = 999;
+const MAX_Y: usize = 400;
+
+/// The cave around the sand source. Column `x` of the cave is column
+/// `x - x_offset` of `tiles`, which is wide enough for sand piling up to the
+/// floor.
+#[derive(Clone)]
+pub struct Map {
+    tiles: Grid<Tile>,
+    x_offset: usize,
+    floor_level: usize,
+}
+
+impl Map {
+    fn get_number_of_sand_at_rest(&self) -> u32 {
+        self.tiles
+            .iter()
+            .filter(|(_, tile)| **tile == Tile::Sand)
+            .count() as u32
+    }
+
+    fn drop_sand_unit(&mut self, has_floor: bool) -> bool {
+        let mut sand_position = (SAND_SOURCE.0 - self.x_offset, SAND_SOURCE.1);
+        'falling: while sand_position.1 <= self.floor_level {
+            let new_y = sand_position.1 + 1;
+            if !(has_floor && new_y == self.floor_level) {
+                let possible_positions = [
+                    (sand_position.0, new_y),
+                    (sand_position.0 - 1, new_y),
+                    (sand_position.0 + 1, new_y),
+                ];
+                for possible_position in possible_positions {
+                    if self.tiles.get(possible_position).unwrap_or(&Tile::Air) == &Tile::Air {
+                        sand_position = possible_position;
+                        continue 'falling;
+                    }
+                }
+            }
+            self.tiles[sand_position] = Tile::Sand;
+            return sand_position != (SAND_SOURCE.0 - self.x_offset, SAND_SOURCE.1);
+        }
+        false
+    }
+
+    /// The part of the cave holding rock or sand, from the top.
+    fn frame(&self) -> Frame {
+        let filled = || {
+            self.tiles
+                .iter()
+                .filter(|(_, tile)| **tile != Tile::Air)
+                .map(|(position, _)| position)
+        };
+        let min_x = filled().map(|(x, _)| x).min().unwrap();
+        let max_x = filled().map(|(x, _)| x).max().unwrap();
+        let max_y = filled().map(|(_, y)| y).max().unwrap();
+        let mut tiles = Grid::new(max_x - min_x + 1, max_y + 1, '.');
+        for (y, row) in self.tiles.rows().take(max_y + 1).enumerate() {
+            for (x, tile) in row[min_x..=max_x].iter().enumerate() {
+                tiles[(x, y)] = tile.symbol();
+            }
+        }
+        Frame {
+            origin: Point2::new((min_x + self.x_offset) as i64, 0),
+            tiles,
+        }
+    }
+}
+
+/// Shows the part of the cave holding rock or sand, from the top.
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.frame().tiles)
+    }
+}
+
+/// Sand pouring in one unit at a time.
+struct Pouring {
+    map: Map,
+    has_floor: bool,
+    is_over: bool,
+}
+
+impl Display for Pouring {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.map)
+    }
+}
+
+impl Simulation for Pouring {
+    fn frame(&self) -> Frame {
+        self.map.frame()
+    }
+
+    fn step(&mut self) -> bool {
+        if self.is_over {
+            return false;
+        }
+        self.is_over = !self.map.drop_sand_unit(self.has_floor);
+        true
+    }
+}
+
+/// Pours sand until it flows into the abyss for part 1, or until it blocks
+/// the source for part 2.
+pub fn simulate(input: &str, part: u8) -> Result<Box<dyn Simulation>, ParseError> {
+    Ok(Box::new(Pouring {
+        map: Map::try_from(input)?,
+        has_floor: part == 2,
+        is_over: false,
+    }))
+}
+
+pub fn palette() -> Palette {
+    Palette::new(
+        Rgb(20, 16, 28),
+        &[
+            ('.', Rgb(20, 16, 28)),
+            ('#', Rgb(110, 104, 120)),
+            ('o', Rgb(232, 196, 104)),
+        ],
+    )
+}
+
+impl TryFrom<&str> for Map {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        // 498,4 -> 498,6 -> 496,6
+        let paths = parse::lines(value, |line| {
+            let start = *line;
+            let mut path = Vec::new();
+            loop {
+                let at = *line;
+                let x = line.number::<usize>()?;
+                if x > MAX_X {
+                    return Err(at.error(format!("an x of at most {}", MAX_X)));
+                }
+                line.tag(",")?;
+                let at = *line;
+                let y = line.number::<usize>()?;
+                if y > MAX_Y {
+                    return Err(at.error(format!("a y of at most {}", MAX_Y)));
+                }
+                path.push((x, y));
+                if !line.eat(" -> ") {
+                    break;
+                }
+            }
+            if path.len() < 2 {
+                return Err(start.error("a rock path of at least two points"));
+            }
+            Ok(path)
+        })?;
+        if paths.is_empty() {
+            return Err(ParseError::at(value, value, "a rock path"));
+        }
+        let rocks: Vec<(usize, usize)> = paths
+            .iter()
+            .flat_map(|path| {
+                path.windows(2).flat_map(|path_slice| {
+                    let (x1, y1) = path_slice[0];
+                    let (x2, y2) = path_slice[1];
+                    let (x1, x2) = (x1.min(x2), x1.max(x2));
+                    let (y1, y2) = (y1.min(y2), y1.max(y2));
+                    (x1..=x2).flat_map(move |x| (y1..=y2).map(move |y| (x, y)))
+                })
+            })
+            .collect();
+        let floor_level = rocks.iter().map(|rock| rock.1).max().unwrap() + 2;
+        // Sand piles up at most as far sideways as it falls.
+        let x_offset = rocks
+            .iter()
+            .map(|rock| rock.0)
+            .chain([SAND_SOURCE.0.saturating_sub(floor_level + 1)])
+            .min()
+            .unwrap();
+        let width = rocks
+            .iter()
+            .map(|rock| rock.0)
+            .chain([SAND_SOURCE.0 + floor_level + 1])
+            .max()
+            .unwrap()
+            - x_offset
+            + 1;
+        let mut tiles = Grid::new(width, floor_level + 1, Tile::Air);
+        for (x, y) in rocks {
+            tiles[(x - x_offset, y)] = Tile::Rock;
+        }
+        Ok(Map {
+            tiles,
+            x_offset,
+            floor_level,
+        })
+    }
+}
+
+/// Generates `size` paths of rock below the source of the sand, each of 1 to
+/// 4 straight lines.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1) as i64;
+    let spread = 10 + size.min(60);
+    let depth = (10 + size).min(170);
+    (0..size)
+        .map(|_| {
+            let (mut x, mut y) = (rng.range(500 - spread..=500 + spread), rng.range(1..=depth));
+            let mut points = vec![format!("{},{}", x, y)];
+            let horizontal = rng.chance(0.5);
+            for line in 0..rng.range(1..=4) {
+                if (line % 2 == 0) == horizontal {
+                    x += rng.range(-8..=8);
+                } else {
+                    y = (y + rng.range(-8..=8)).max(1);
+                }
+                points.push(format!("{},{}", x, y));
+            }
+            points.join(" -> ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paths_have_two_points() {
+        let error = Day14::parse("498,4 -> 498,6\n498,4").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "a rock path of at least two points");
+    }
+
+    #[test]
+    fn coordinates_are_bounded() {
+        let error = Day14::parse("498,4 -> 498,4000").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 14));
+        let error = Day14::parse("4980000,4 -> 498,4").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 1));
+    }
+}
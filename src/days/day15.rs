@@ -0,0 +1,232 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    generate::Rng,
+    parse::{self, Cursor, ParseError},
+    solution::{Answer, Solution},
+};
+
+const SEARCH_LIMIT: i32 = 4_000_000;
+/// The example puzzle searches a much smaller square than the real one.
+const EXAMPLE_SEARCH_LIMIT: i32 = 20;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = Map;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Map::try_from(input)
+    }
+
+    fn part1(map: &Self::Input<'_>) -> Answer {
+        count_scanned_positions(map, search_limit(map) / 2).into()
+    }
+
+    fn part2(map: &Self::Input<'_>) -> Answer {
+        find_tuning_frequency(map, search_limit(map)).into()
+    }
+}
+
+/// The size of the square the distress beacon is in, whose middle row is the
+/// one checked in part 1. Inputs whose sensors all lie in the small square of
+/// the example are taken to be the example.
+fn search_limit(map: &Map) -> i32 {
+    let example = 0..=EXAMPLE_SEARCH_LIMIT;
+    if map
+        .sensor_area
+        .keys()
+        .all(|(x, y)| example.contains(x) && example.contains(y))
+    {
+        EXAMPLE_SEARCH_LIMIT
+    } else {
+        SEARCH_LIMIT
+    }
+}
+
+fn count_scanned_positions(map: &Map, row_to_check: i32) -> u32 {
+    let mut scanned: Vec<(i32, i32)> = map
+        .sensor_area
+        .iter()
+        .filter_map(|(sensor_position, range)| {
+            let spread = range - (sensor_position.1 - row_to_check).abs();
+            (spread >= 0).then_some((sensor_position.0 - spread, sensor_position.0 + spread))
+        })
+        .collect();
+    scanned.sort_unstable();
+    let mut merged: Vec<(i32, i32)> = Vec::new();
+    for (left, right) in scanned {
+        match merged.last_mut() {
+            Some(last) if left <= last.1 + 1 => last.1 = last.1.max(right),
+            _ => merged.push((left, right)),
+        }
+    }
+    let beacons = map
+        .known_beacons
+        .iter()
+        .filter(|(x, y)| {
+            *y == row_to_check
+                && merged
+                    .iter()
+                    .any(|(left, right)| (left..=right).contains(&x))
+        })
+        .count();
+    let positions: u32 = merged
+        .iter()
+        .map(|(left, right)| (right - left + 1) as u32)
+        .sum();
+    positions - beacons as u32
+}
+
+/// Finds the one position of the square from 0 to `limit` that no sensor
+/// covers. Being just out of reach of the sensors around it, it lies where
+/// two of the diagonals just outside their reach cross, or where one of them
+/// crosses an edge of the square, or in a corner.
+fn find_tuning_frequency(map: &Map, limit: i32) -> u64 {
+    let limit = limit as i64;
+    // Diagonals as the constant `y - x` of the rising ones and `y + x` of the
+    // falling ones.
+    let mut rising = Vec::new();
+    let mut falling = Vec::new();
+    for (&(x, y), &range) in &map.sensor_area {
+        let (x, y, reach) = (x as i64, y as i64, range as i64 + 1);
+        rising.extend([y - x - reach, y - x + reach]);
+        falling.extend([y + x - reach, y + x + reach]);
+    }
+    let mut candidates = vec![(0, 0), (0, limit), (limit, 0), (limit, limit)];
+    for &a in &rising {
+        candidates.extend(
+            falling
+                .iter()
+                .filter(|b| (*b - a) % 2 == 0)
+                .map(|b| ((b - a) / 2, (a + b) / 2)),
+        );
+        candidates.extend([(0, a), (limit, limit + a), (-a, 0), (limit - a, limit)]);
+    }
+    for &b in &falling {
+        candidates.extend([(0, b), (limit, b - limit), (b, 0), (b - limit, limit)]);
+    }
+    let (x, y) = candidates
+        .into_iter()
+        .find(|&(x, y)| {
+            (0..=limit).contains(&x)
+                && (0..=limit).contains(&y)
+                && map.sensor_area.iter().all(|(&(sx, sy), &range)| {
+                    (sx as i64 - x).abs() + (sy as i64 - y).abs() > range as i64
+                })
+        })
+        .expect("a position of the square is out of reach of every sensor");
+    x as u64 * 4_000_000 + y as u64
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Map {
+    sensor_area: HashMap<(i32, i32), i32>,
+    known_beacons: HashSet<(i32, i32)>,
+}
+
+// x=0, y=11
+fn parse_location(cursor: &mut Cursor) -> Result<(i32, i32), ParseError> {
+    cursor.tag("x=")?;
+    let x = cursor.number()?;
+    cursor.tag(", y=")?;
+    let y = cursor.number()?;
+    Ok((x, y))
+}
+
+impl TryFrom<&str> for Map {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut map = Map {
+            sensor_area: HashMap::new(),
+            known_beacons: HashSet::new(),
+        };
+        for (sensor_position, beacon_position) in parse::lines(value, |line| {
+            line.tag("Sensor at ")?;
+            let sensor = parse_location(line)?;
+            line.tag(": closest beacon is at ")?;
+            let beacon = parse_location(line)?;
+            Ok((sensor, beacon))
+        })? {
+            let distance = (sensor_position.0 - beacon_position.0).abs()
+                + (sensor_position.1 - beacon_position.1).abs();
+            map.sensor_area.insert(sensor_position, distance);
+            map.known_beacons.insert(beacon_position);
+        }
+        Ok(map)
+    }
+}
+
+/// Generates the reports of `size` sensors, at least 4, which leave a single
+/// position of the search square uncovered for the distress beacon. Four
+/// sensors on its diagonals cover the whole square but that position, and
+/// every sensor's closest beacon is next to it.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let limit = SEARCH_LIMIT as i64;
+    let distress = (rng.range(0..=limit), rng.range(0..=limit));
+    // Far enough from the distress beacon to cover the farthest corner.
+    let reach = [
+        distress.0,
+        limit - distress.0,
+        distress.1,
+        limit - distress.1,
+    ]
+    .into_iter()
+    .max()
+    .expect("there are four sides")
+        + 1;
+    let mut sensors: Vec<(i64, i64)> = [(1, 1), (1, -1), (-1, 1), (-1, -1)]
+        .map(|(dx, dy)| (distress.0 + dx * reach, distress.1 + dy * reach))
+        .to_vec();
+    while sensors.len() < size.max(4) {
+        let sensor = (rng.range(0..=limit), rng.range(0..=limit));
+        if sensor != distress && !sensors.contains(&sensor) {
+            sensors.push(sensor);
+        }
+    }
+    rng.shuffle(&mut sensors);
+    sensors
+        .iter()
+        .map(|&(x, y)| {
+            // The neighbour of the distress beacon on the sensor's side is
+            // just closer to the sensor.
+            let (dx, dy) = (x - distress.0, y - distress.1);
+            let beacon = if dx.abs() >= dy.abs() {
+                (distress.0 + dx.signum(), distress.1)
+            } else {
+                (distress.0, distress.1 + dy.signum())
+            };
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                x, y, beacon.0, beacon.1
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_from_str() {
+        let map = Map::try_from("Sensor at x=2, y=18: closest beacon is at x=-2, y=15\nSensor at x=9, y=16: closest beacon is at x=10, y=16").unwrap();
+        assert_eq!(
+            map,
+            Map {
+                sensor_area: HashMap::from([((2, 18), 7), ((9, 16), 1),]),
+                known_beacons: HashSet::from([(10, 16), (-2, 15),]),
+            }
+        )
+    }
+
+    #[test]
+    fn test_map_from_malformed_str() {
+        let error =
+            Map::try_from("Sensor at x=2, y=18: closest beacon is at x=-2 y=15").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 47));
+        assert_eq!(error.expected, "\", y=\"");
+    }
+}
@@ -0,0 +1,301 @@
+use std::collections::HashMap;
+
+use crate::{
+    generate::Rng,
+    parse::{self, ParseError},
+    search,
+    solution::{Answer, Solution},
+};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = Cave<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(cave: &Self::Input<'_>) -> Answer {
+        cave.most_pressure_by_opened_valves(30)
+            .into_values()
+            .max()
+            .unwrap_or(0)
+            .into()
+    }
+
+    fn part2(cave: &Self::Input<'_>) -> Answer {
+        let most_pressure = cave.most_pressure_by_opened_valves(26);
+        // The most pressure released opening any subset of each set of valves.
+        let mut most_pressure_within = vec![0; 1 << cave.valves.len()];
+        for (&opened, &pressure) in &most_pressure {
+            most_pressure_within[opened as usize] = pressure;
+        }
+        for valve in 0..cave.valves.len() {
+            for valves in 0..most_pressure_within.len() {
+                if valves & (1 << valve) != 0 {
+                    most_pressure_within[valves] = most_pressure_within[valves]
+                        .max(most_pressure_within[valves ^ (1 << valve)]);
+                }
+            }
+        }
+        // You and the elephant open disjoint sets of valves.
+        let all_valves = most_pressure_within.len() - 1;
+        most_pressure
+            .iter()
+            .map(|(&mine, &pressure)| pressure + most_pressure_within[all_valves ^ mine as usize])
+            .max()
+            .unwrap_or(0)
+            .into()
+    }
+}
+
+const START: &str = "AA";
+const MAX_USEFUL_VALVES: usize = u16::BITS as usize;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Valve<'a> {
+    name: &'a str,
+    flow_rate: u32,
+    tunnels: Vec<&'a str>,
+}
+
+/// The valves worth opening, with the minutes it takes to walk between them.
+pub struct Cave<'a> {
+    /// The valves with a positive flow rate; valve `i` is bit `i` of a set of
+    /// opened valves.
+    valves: Vec<Valve<'a>>,
+    /// Minutes from the start to each valve.
+    from_start: Vec<u32>,
+    /// Minutes from each valve to each other valve.
+    between: Vec<Vec<u32>>,
+}
+
+impl<'a> Cave<'a> {
+    fn new(valves: Vec<Valve<'a>>) -> Self {
+        let by_name: HashMap<&str, &Valve> =
+            valves.iter().map(|valve| (valve.name, valve)).collect();
+        let minutes_from =
+            |name: &'a str| search::distances(name, |name| by_name[name].tunnels.clone());
+        let useful: Vec<Valve> = valves
+            .iter()
+            .filter(|valve| valve.flow_rate > 0)
+            .cloned()
+            .collect();
+        let minutes_to_useful = |name| {
+            let minutes = minutes_from(name);
+            useful
+                .iter()
+                .map(|valve| minutes.get(valve.name).map_or(u32::MAX, |&m| m as u32))
+                .collect::<Vec<_>>()
+        };
+        Cave {
+            from_start: minutes_to_useful(START),
+            between: useful
+                .iter()
+                .map(|valve| minutes_to_useful(valve.name))
+                .collect(),
+            valves: useful,
+        }
+    }
+
+    /// The most pressure released in `minutes` for every set of opened valves
+    /// that can be reached.
+    fn most_pressure_by_opened_valves(&self, minutes: u32) -> HashMap<u16, u32> {
+        let mut most_pressure = HashMap::new();
+        self.visit(None, minutes, 0, 0, &mut most_pressure, &mut HashMap::new());
+        most_pressure
+    }
+
+    /// Walks on from valve `at` to open every valve worth opening in turn.
+    /// A walk reaching a valve with as many minutes left and the same valves
+    /// opened as an earlier one, but no more pressure released, cannot do
+    /// better than it and is cut short.
+    fn visit(
+        &self,
+        at: Option<usize>,
+        minutes_left: u32,
+        opened: u16,
+        pressure: u32,
+        most_pressure: &mut HashMap<u16, u32>,
+        seen: &mut HashMap<(Option<usize>, u32, u16), u32>,
+    ) {
+        match seen.get(&(at, minutes_left, opened)) {
+            Some(&best) if best >= pressure => return,
+            _ => {
+                seen.insert((at, minutes_left, opened), pressure);
+            }
+        }
+        let best = most_pressure.entry(opened).or_insert(0);
+        *best = (*best).max(pressure);
+        for (next, valve) in self.valves.iter().enumerate() {
+            let walk = match at {
+                None => self.from_start[next],
+                Some(at) => self.between[at][next],
+            };
+            if opened & (1 << next) != 0 || walk.saturating_add(1) >= minutes_left {
+                continue;
+            }
+            let minutes_left = minutes_left - walk - 1;
+            self.visit(
+                Some(next),
+                minutes_left,
+                opened | (1 << next),
+                pressure + valve.flow_rate * minutes_left,
+                most_pressure,
+                seen,
+            );
+        }
+    }
+}
+
+// Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+fn parse_input(input: &str) -> Result<Cave<'_>, ParseError> {
+    let valves = parse::lines(input, |line| {
+        line.tag("Valve ")?;
+        let name = line.word()?;
+        line.tag(" has flow rate=")?;
+        let flow_rate = line.number()?;
+        if !line.eat("; tunnels lead to valves ") {
+            line.tag("; tunnel leads to valve ")?;
+        }
+        let mut other_valves = Vec::new();
+        loop {
+            let at = *line;
+            other_valves.push((line.word()?, at));
+            if !line.eat(", ") {
+                break;
+            }
+        }
+        Ok((name, flow_rate, other_valves))
+    })?;
+    for (_, _, other_valves) in &valves {
+        for (other_valve, at) in other_valves {
+            if !valves.iter().any(|(name, _, _)| name == other_valve) {
+                return Err(at.error("the name of a listed valve"));
+            }
+        }
+    }
+    if !valves.iter().any(|(name, _, _)| *name == START) {
+        return Err(ParseError::at(input, &input[input.len()..], "valve AA"));
+    }
+    // The opened valves are kept in 16 bits, one for each valve worth opening.
+    if let Some(line) = input
+        .lines()
+        .zip(&valves)
+        .filter(|(_, (_, flow_rate, _))| *flow_rate > 0)
+        .map(|(line, _)| line)
+        .nth(MAX_USEFUL_VALVES)
+    {
+        return Err(ParseError::at(
+            input,
+            line,
+            format!("at most {} valves with a flow rate", MAX_USEFUL_VALVES),
+        ));
+    }
+    let valves = valves
+        .into_iter()
+        .map(|(name, flow_rate, other_valves)| Valve {
+            name,
+            flow_rate,
+            tunnels: other_valves.into_iter().map(|(name, _)| name).collect(),
+        })
+        .collect();
+    Ok(Cave::new(valves))
+}
+
+/// Generates a scan of `size` valves, at least 2, connected by tunnels both
+/// ways. At most 15 valves besides AA release pressure, as the solution keeps
+/// the opened valves in 16 bits.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let count = size.clamp(2, 26 * 26);
+    let mut names: Vec<String> = (b'A'..=b'Z')
+        .flat_map(|first| {
+            (b'A'..=b'Z').map(move |second| {
+                String::from_utf8(vec![first, second]).expect("letters are ASCII")
+            })
+        })
+        .filter(|name| name != START)
+        .collect();
+    rng.shuffle(&mut names);
+    names.truncate(count - 1);
+    names.insert(0, START.to_string());
+    let mut tunnels = vec![Vec::new(); count];
+    let mut connect = |from: usize, to: usize| {
+        if from != to && !tunnels[from].contains(&to) {
+            tunnels[from].push(to);
+            tunnels[to].push(from);
+        }
+    };
+    // As in the puzzle, the tunnels are long corridors: each valve leads on
+    // from one of the few valves before it, and a few shortcuts make loops.
+    for valve in 1..count {
+        connect(valve, valve - 1 - rng.below(valve.min(3)));
+    }
+    for _ in 0..count / 10 {
+        let valve = rng.below(count);
+        connect(valve, valve.saturating_sub(rng.below(8) + 2));
+    }
+    // The valves releasing pressure are spread along the corridors.
+    let mut useful: Vec<usize> = (1..count).collect();
+    rng.shuffle(&mut useful);
+    useful.truncate(15);
+    let flow_rates: Vec<i64> = (0..count)
+        .map(|valve| match useful.contains(&valve) {
+            true => rng.range(1..=25),
+            false => 0,
+        })
+        .collect();
+    let mut scan: Vec<String> = (0..count)
+        .map(|valve| {
+            let others: Vec<&str> = tunnels[valve]
+                .iter()
+                .map(|&other| names[other].as_str())
+                .collect();
+            let tunnels = match others.len() {
+                1 => "tunnel leads to valve",
+                _ => "tunnels lead to valves",
+            };
+            format!(
+                "Valve {} has flow rate={}; {} {}",
+                names[valve],
+                flow_rates[valve],
+                tunnels,
+                others.join(", ")
+            )
+        })
+        .collect();
+    rng.shuffle(&mut scan);
+    scan.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn at_most_sixteen_valves_release_pressure() {
+        let scan = |useful: usize| {
+            let names: Vec<String> = (0..=useful)
+                .map(|i| match i {
+                    0 => START.to_string(),
+                    _ => format!("B{}", (b'A' + i as u8) as char),
+                })
+                .collect();
+            (0..=useful)
+                .map(|i| {
+                    format!(
+                        "Valve {} has flow rate={}; tunnel leads to valve {}",
+                        names[i],
+                        i.min(1),
+                        names[(i + 1) % names.len()]
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        assert!(parse_input(&scan(16)).is_ok());
+        let error = parse_input(&scan(17)).err().unwrap();
+        assert_eq!((error.line, error.column), (18, 1));
+    }
+}
@@ -0,0 +1,419 @@
+use std::{fmt::Display, iter::Cycle, ops::Range};
+
+use log::{debug, trace};
+
+use crate::{
+    cycle,
+    generate::Rng,
+    geometry::Point2,
+    grid::Grid,
+    parse::{Cursor, ParseError},
+    solution::{Answer, Solution},
+    visualize::{Frame, Palette, Rgb, Simulation},
+};
+
+type Position = (usize, usize);
+
+#[derive(Clone, Copy)]
+struct Rock {
+    body: &'static [Position],
+    is_falling: bool,
+}
+
+impl Rock {
+    fn width(&self) -> usize {
+        self.body.iter().map(|(_, col)| col).max().unwrap() + 1
+    }
+    fn height(&self) -> usize {
+        self.body.iter().map(|(row, _)| row).max().unwrap() + 1
+    }
+}
+
+impl Display for Rock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut grid = Grid::new(self.width(), self.height(), '.');
+        for (row, col) in self.body {
+            grid[(*col, *row)] = if self.is_falling { '@' } else { '#' };
+        }
+        for row in grid.rows().rev() {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+const ROCKS: [Rock; 5] = [
+    Rock {
+        body: &[(0, 0), (0, 1), (0, 2), (0, 3)],
+        is_falling: false,
+    },
+    Rock {
+        body: &[(0, 1), (1, 0), (1, 1), (1, 2), (2, 1)],
+        is_falling: false,
+    },
+    Rock {
+        body: &[(2, 2), (1, 2), (0, 0), (0, 1), (0, 2)],
+        is_falling: false,
+    },
+    Rock {
+        body: &[(0, 0), (1, 0), (2, 0), (3, 0)],
+        is_falling: false,
+    },
+    Rock {
+        body: &[(0, 0), (0, 1), (1, 0), (1, 1)],
+        is_falling: false,
+    },
+];
+
+const PART_1_ROCKS: usize = 2022;
+/// How deep below the top of the tower the rocks can still make a difference
+/// to the rocks falling next.
+const SURFACE_DEPTH: usize = 64;
+
+#[derive(Clone, Copy)]
+pub enum Jet {
+    Left,
+    Right,
+}
+
+fn parse_jets(input: &str) -> Result<Vec<Jet>, ParseError> {
+    let mut cursor = Cursor::new(input);
+    let mut jets = Vec::new();
+    // There is at least one jet for the pattern to repeat.
+    loop {
+        jets.push(cursor.one_of(&[('<', Jet::Left), ('>', Jet::Right)])?);
+        if cursor.is_at_end() {
+            break Ok(jets);
+        }
+    }
+}
+
+impl Display for Jet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Jet::Left => "<",
+            Jet::Right => ">",
+        })
+    }
+}
+
+struct Chamber<JetIter: Clone + Iterator<Item = Jet>, RockIter: Clone + Iterator<Item = Rock>> {
+    width: usize,
+    rocks: Vec<(Position, Rock)>,
+    /// Which cells the settled rocks take, with `(x, y)` standing for column
+    /// `x` of row `trimmed_rows + y`, up to the highest settled point.
+    settled: Grid<bool>,
+    /// How many rows at the bottom of the chamber were forgotten, all of them
+    /// below a full row.
+    trimmed_rows: usize,
+    jet_pattern: Cycle<JetIter>,
+    rocks_to_drop: Cycle<RockIter>,
+    spawned_rocks: usize,
+    jets_blown: usize,
+}
+
+impl<JetIter: Clone + Iterator<Item = Jet>, RockIter: Clone + Iterator<Item = Rock>>
+    Chamber<JetIter, RockIter>
+{
+    fn new(width: usize, jet_pattern: Cycle<JetIter>, rocks_to_drop: Cycle<RockIter>) -> Self {
+        let mut chamber = Chamber {
+            width,
+            rocks: vec![],
+            settled: Grid::new(width, 0, false),
+            trimmed_rows: 0,
+            jet_pattern,
+            rocks_to_drop,
+            spawned_rocks: 0,
+            jets_blown: 0,
+        };
+        chamber.spawn_rock();
+        chamber
+    }
+
+    fn spawn_rock(&mut self) {
+        let rock = self.rocks_to_drop.next().unwrap();
+        let offset = (self.highest_point() + 3, 2);
+        self.rocks.push((
+            offset,
+            Rock {
+                is_falling: true,
+                ..rock
+            },
+        ));
+        self.spawned_rocks += 1;
+    }
+
+    fn highest_point(&self) -> usize {
+        self.rocks
+            .iter()
+            .map(|((row, _), rock)| row + rock.height())
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn highest_point_of_settled_rocks(&self) -> usize {
+        self.trimmed_rows + self.settled.height()
+    }
+
+    /// Whether a settled rock takes `position`. The forgotten rows count as
+    /// taken, as no rock can fall past the full row above them.
+    fn is_occupied(&self, (row, col): Position) -> bool {
+        match row.checked_sub(self.trimmed_rows) {
+            Some(y) => self.settled.get((col, y)).copied().unwrap_or(false),
+            None => true,
+        }
+    }
+
+    /// Whether `rock` fits at `offset` without overlapping a settled rock.
+    fn fits(&self, rock: &Rock, offset: Position) -> bool {
+        rock.body
+            .iter()
+            .all(|(row, col)| !self.is_occupied((offset.0 + row, offset.1 + col)))
+    }
+
+    fn tick(&mut self) {
+        let (mut offset, rock) = *self.rocks.last().unwrap();
+        assert!(rock.is_falling, "last rock isn't falling");
+        let jet = self.jet_pattern.next().unwrap();
+        self.jets_blown += 1;
+        let pushed = match jet {
+            Jet::Left => offset.1.checked_sub(1),
+            Jet::Right => Some(offset.1 + 1).filter(|col| col + rock.width() <= self.width),
+        };
+        if let Some(col) = pushed.filter(|&col| self.fits(&rock, (offset.0, col))) {
+            offset.1 = col;
+        }
+        let fallen = offset
+            .0
+            .checked_sub(1)
+            .filter(|&row| self.fits(&rock, (row, offset.1)));
+        match fallen {
+            Some(row) => self.rocks.last_mut().unwrap().0 = (row, offset.1),
+            None => {
+                self.rocks.last_mut().unwrap().0 = offset;
+                self.settle();
+            }
+        }
+    }
+
+    /// Settles the falling rock and spawns the next one.
+    fn settle(&mut self) {
+        let falling_rock = self.rocks.last_mut().unwrap();
+        falling_rock.1.is_falling = false;
+        let (offset, rock) = *falling_rock;
+        trace!(
+            "rock {} settles at row {}, column {}",
+            self.spawned_rocks,
+            offset.0,
+            offset.1
+        );
+        let row = offset.0 - self.trimmed_rows;
+        self.settled.extend_rows(row + rock.height(), false);
+        for (r, c) in rock.body {
+            self.settled[(offset.1 + c, row + r)] = true;
+        }
+        self.trim(offset.0..offset.0 + rock.height());
+        self.spawn_rock();
+    }
+
+    /// Ticks until the falling rock settles and the next one spawns.
+    fn drop_rock(&mut self) {
+        let spawned_rocks = self.spawned_rocks;
+        while self.spawned_rocks == spawned_rocks {
+            self.tick();
+        }
+    }
+
+    /// How far below the highest settled point the top of each column is, up
+    /// to `SURFACE_DEPTH`: a column some jet patterns never fill would
+    /// otherwise only ever get deeper and keep the surface from repeating.
+    fn surface(&self) -> Vec<usize> {
+        let highest_point = self.highest_point_of_settled_rocks();
+        (0..self.width)
+            .map(|col| {
+                (0..highest_point)
+                    .rev()
+                    .take(SURFACE_DEPTH)
+                    .position(|row| self.is_occupied((row, col)))
+                    .unwrap_or(SURFACE_DEPTH)
+            })
+            .collect()
+    }
+
+    /// Forgets the rocks below the highest full row among `rows`, the rows
+    /// of the rock that just settled, as no rock can fall past it.
+    fn trim(&mut self, rows: Range<usize>) {
+        let Some(full_row) = rows
+            .rev()
+            .find(|&row| (0..self.width).all(|col| self.is_occupied((row, col))))
+        else {
+            return;
+        };
+        self.rocks
+            .retain(|(offset, rock)| rock.is_falling || offset.0 + rock.height() >= full_row);
+        self.settled.remove_rows(full_row - self.trimmed_rows);
+        self.trimmed_rows = full_row;
+    }
+}
+
+impl<JetIter: Clone + Iterator<Item = Jet>, RockIter: Clone + Iterator<Item = Rock>> Display
+    for Chamber<JetIter, RockIter>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Simulation::frame(self).tiles)
+    }
+}
+
+/// Drops the rocks of part 1 one at a time.
+impl<JetIter: Clone + Iterator<Item = Jet>, RockIter: Clone + Iterator<Item = Rock>> Simulation
+    for Chamber<JetIter, RockIter>
+{
+    /// The chamber with its walls and floor, the floor at `y = 0`.
+    fn frame(&self) -> Frame {
+        let height = self.highest_point();
+        let mut tiles = Grid::new(self.width + 2, height + 1, '.');
+        for y in 0..height {
+            tiles[(0, y)] = '|';
+            tiles[(self.width + 1, y)] = '|';
+        }
+        for x in 1..=self.width {
+            tiles[(x, height)] = '-';
+        }
+        tiles[(0, height)] = '+';
+        tiles[(self.width + 1, height)] = '+';
+        for ((row, col), rock) in &self.rocks {
+            for (r, c) in rock.body {
+                tiles[(col + c + 1, height - 1 - (row + r))] =
+                    if rock.is_falling { '@' } else { '#' };
+            }
+        }
+        Frame {
+            origin: Point2::new(-1, -(height as i64)),
+            tiles,
+        }
+    }
+
+    fn step(&mut self) -> bool {
+        if self.spawned_rocks > PART_1_ROCKS {
+            return false;
+        }
+        self.drop_rock();
+        true
+    }
+}
+
+/// Both parts drop the same rocks, so both show the rocks of part 1.
+pub fn simulate(input: &str, _part: u8) -> Result<Box<dyn Simulation>, ParseError> {
+    let jets = parse_jets(input)?;
+    Ok(Box::new(Chamber::new(
+        7,
+        jets.into_iter().cycle(),
+        ROCKS.into_iter().cycle(),
+    )))
+}
+
+pub fn palette() -> Palette {
+    Palette::new(
+        Rgb(16, 18, 24),
+        &[
+            ('.', Rgb(16, 18, 24)),
+            ('#', Rgb(140, 146, 160)),
+            ('@', Rgb(240, 128, 48)),
+            ('|', Rgb(92, 72, 56)),
+            ('-', Rgb(92, 72, 56)),
+            ('+', Rgb(92, 72, 56)),
+        ],
+    )
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input<'a> = Vec<Jet>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_jets(input)
+    }
+
+    fn part1(jets: &Self::Input<'_>) -> Answer {
+        let mut chamber = Chamber::new(7, jets.iter().copied().cycle(), ROCKS.into_iter().cycle());
+        while chamber.spawned_rocks <= PART_1_ROCKS {
+            chamber.tick();
+        }
+        chamber.highest_point_of_settled_rocks().into()
+    }
+
+    fn part2(jets: &Self::Input<'_>) -> Answer {
+        let mut chamber = Chamber::new(7, jets.iter().copied().cycle(), ROCKS.into_iter().cycle());
+        // The tower grows the same way whenever the next rock, the next jet
+        // and the shape of its top come round again.
+        let (height, repetition) = cycle::extrapolate(
+            &mut chamber,
+            1_000_000_000_000,
+            Chamber::drop_rock,
+            |chamber| {
+                (
+                    chamber.spawned_rocks % ROCKS.len(),
+                    chamber.jets_blown % jets.len(),
+                    chamber.surface(),
+                )
+            },
+            Chamber::highest_point_of_settled_rocks,
+        );
+        if let Some(repetition) = repetition {
+            debug!(
+                "the tower repeats every {} rocks from rock {} on",
+                repetition.length, repetition.start
+            );
+        }
+        height.into()
+    }
+}
+
+/// Generates a pattern of `size` jets.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| if rng.chance(0.5) { '<' } else { '>' })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn rocks_are_correct() {
+        for rock in ROCKS {
+            println!("{}", rock);
+        }
+    }
+
+    #[test]
+    fn jet_patterns_are_not_empty() {
+        let error = parse_jets("").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert!(parse_jets("<>").is_ok());
+    }
+
+    #[test]
+    fn spawn_rock() {
+        let chamber = Chamber::new(
+            7,
+            vec![Jet::Left, Jet::Right].into_iter().cycle(),
+            ROCKS.into_iter().cycle(),
+        );
+        println!("{}", chamber);
+    }
+
+    #[test]
+    fn tick() {
+        let mut chamber = Chamber::new(
+            7,
+            vec![Jet::Left, Jet::Right].into_iter().cycle(),
+            ROCKS.into_iter().cycle(),
+        );
+        chamber.tick();
+        println!("{}", chamber);
+    }
+}
@@ -0,0 +1,65 @@
+use std::collections::BTreeSet;
+
+use crate::{
+    generate::Rng,
+    geometry::Point3,
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input<'a> = BTreeSet<Point3>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let droplets = parse::lines(input, |line| {
+            let x = line.number()?;
+            line.tag(",")?;
+            let y = line.number()?;
+            line.tag(",")?;
+            let z = line.number()?;
+            Ok(Point3::new(x, y, z))
+        })?;
+        Ok(droplets.into_iter().collect())
+    }
+
+    fn part1(droplets: &Self::Input<'_>) -> Answer {
+        droplets
+            .iter()
+            .map(|droplet| {
+                droplet
+                    .neighbours6()
+                    .iter()
+                    .filter(|neighbor| droplets.contains(neighbor))
+                    .count()
+            })
+            .map(|neighbors| 6 - neighbors)
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(_droplets: &Self::Input<'_>) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+/// Generates a droplet of `size` distinct cubes, scattered in a box four
+/// times their volume so that it has pockets of air.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let count = size.max(1);
+    let side = ((4 * count) as f64).cbrt().ceil() as i64;
+    let mut cubes = BTreeSet::new();
+    let mut scan = Vec::new();
+    while cubes.len() < count {
+        let cube = (
+            rng.range(0..=side - 1),
+            rng.range(0..=side - 1),
+            rng.range(0..=side - 1),
+        );
+        if cubes.insert(cube) {
+            scan.push(format!("{},{},{}", cube.0, cube.1, cube.2));
+        }
+    }
+    scan.join("\n")
+}
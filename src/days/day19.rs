@@ -3,21 +3,13 @@ use std::{
     ops::{Add, AddAssign, Sub, SubAssign},
 };
 
-use nom::{
-    bytes::{complete::take_while, streaming::tag},
-    character::is_digit,
-    combinator::map_res,
-    sequence::{preceded, separated_pair, terminated},
-    IResult,
-};
+use log::{debug, trace};
 
-#[derive(Debug, PartialEq)]
-enum Resource {
-    Ore(u32),
-    Clay(u32),
-    Obsidian(u32),
-    Geode(u32),
-}
+use crate::{
+    generate::Rng,
+    parse::{self, Cursor, ParseError},
+    solution::{Answer, Solution},
+};
 
 enum Robot {
     GeodeCracking,
@@ -36,7 +28,7 @@ impl Display for Robot {
     }
 }
 
-#[derive(Debug, PartialEq, Default, Copy, Clone, PartialOrd)]
+#[derive(Debug, PartialEq, Default, Copy, Clone)]
 struct Stock {
     ore: u32,
     clay: u32,
@@ -112,13 +104,13 @@ impl Stock {
             geode: 0,
         }
     }
-    fn add(&mut self, resource: &Resource) {
-        match resource {
-            Resource::Ore(amount) => self.ore += amount,
-            Resource::Clay(amount) => self.clay += amount,
-            Resource::Obsidian(amount) => self.obsidian += amount,
-            Resource::Geode(amount) => self.geode += amount,
-        }
+
+    /// Whether there is at least as much of every resource as `cost` takes.
+    fn covers(&self, cost: &Stock) -> bool {
+        self.ore >= cost.ore
+            && self.clay >= cost.clay
+            && self.obsidian >= cost.obsidian
+            && self.geode >= cost.geode
     }
 }
 
@@ -139,7 +131,7 @@ impl Factory {
         match robot {
             Some(Robot::GeodeCracking) if self.blueprint.can_create_geode_robot(&self.stock) => {
                 self.stock -= self.blueprint.geode_cracking_robot_cost;
-                println!(
+                trace!(
                     "Spend {} ore and {} obsidian to start building a geode-cracking robot.",
                     self.blueprint.geode_cracking_robot_cost.ore,
                     self.blueprint.geode_cracking_robot_cost.obsidian
@@ -151,7 +143,7 @@ impl Factory {
             {
                 self.stock.ore -= self.blueprint.obsidian_collecting_robot_cost.ore;
                 self.stock.clay -= self.blueprint.obsidian_collecting_robot_cost.clay;
-                println!(
+                trace!(
                     "Spend {} ore and {} clay to start building a obsidian-collecting robot.",
                     self.blueprint.obsidian_collecting_robot_cost.ore,
                     self.blueprint.obsidian_collecting_robot_cost.clay
@@ -160,7 +152,7 @@ impl Factory {
             }
             Some(Robot::ClayCollecting) if self.blueprint.can_create_clay_robot(&self.stock) => {
                 self.stock.ore -= self.blueprint.clay_collecting_robot_cost.ore;
-                println!(
+                trace!(
                     "Spend {} ore to start building a clay-collecting robot.",
                     self.blueprint.clay_collecting_robot_cost.ore
                 );
@@ -168,7 +160,7 @@ impl Factory {
             }
             Some(Robot::OreCollecting) if self.blueprint.can_create_ore_robot(&self.stock) => {
                 self.stock.ore -= self.blueprint.ore_collecting_robot_cost.ore;
-                println!(
+                trace!(
                     "Spend {} ore to start building a ore-collecting robot.",
                     self.blueprint.ore_collecting_robot_cost.ore
                 );
@@ -179,8 +171,8 @@ impl Factory {
     }
 }
 
-#[derive(Debug, PartialEq)]
-struct Blueprint {
+#[derive(Debug, PartialEq, Clone)]
+pub struct Blueprint {
     id: u32,
     ore_collecting_robot_cost: Stock,
     clay_collecting_robot_cost: Stock,
@@ -197,10 +189,9 @@ impl Blueprint {
                     .then(|| {
                         self.ore_collecting_robot_cost
                             .ore
-                            .checked_sub(stock.ore)
-                            .unwrap_or(0)
+                            .saturating_sub(stock.ore)
                             .checked_div(robots.ore_collecting)
-                            .and_then(|time| Some(time * 4))
+                            .map(|time| time * 4)
                     })
                     .flatten(),
             ),
@@ -210,10 +201,9 @@ impl Blueprint {
                     .then(|| {
                         self.clay_collecting_robot_cost
                             .ore
-                            .checked_sub(stock.ore)
-                            .unwrap_or(0)
+                            .saturating_sub(stock.ore)
                             .checked_div(robots.ore_collecting)
-                            .and_then(|time| Some(time * 3))
+                            .map(|time| time * 3)
                     })
                     .flatten(),
             ),
@@ -223,17 +213,15 @@ impl Blueprint {
                     .then(|| {
                         self.obsidian_collecting_robot_cost
                             .ore
-                            .checked_sub(stock.ore)
-                            .unwrap_or(0)
+                            .saturating_sub(stock.ore)
                             .checked_div(robots.ore_collecting)
                             .max(
                                 self.obsidian_collecting_robot_cost
                                     .clay
-                                    .checked_sub(stock.clay)
-                                    .unwrap_or(0)
+                                    .saturating_sub(stock.clay)
                                     .checked_div(robots.clay_collecting),
                             )
-                            .and_then(|time| Some(time * 2))
+                            .map(|time| time * 2)
                     })
                     .flatten(),
             ),
@@ -241,14 +229,12 @@ impl Blueprint {
                 Robot::GeodeCracking,
                 self.geode_cracking_robot_cost
                     .ore
-                    .checked_sub(stock.ore)
-                    .unwrap_or(0)
+                    .saturating_sub(stock.ore)
                     .checked_div(robots.ore_collecting)
                     .max(
                         self.geode_cracking_robot_cost
                             .obsidian
-                            .checked_sub(stock.obsidian)
-                            .unwrap_or(0)
+                            .saturating_sub(stock.obsidian)
                             .checked_div(robots.obsidian_collecting),
                     ),
             ),
@@ -259,25 +245,19 @@ impl Blueprint {
     }
 
     fn can_create_geode_robot(&self, stock: &Stock) -> bool {
-        stock >= &self.geode_cracking_robot_cost
+        stock.covers(&self.geode_cracking_robot_cost)
     }
     fn can_create_obsidian_robot(&self, stock: &Stock) -> bool {
-        stock >= &self.obsidian_collecting_robot_cost
+        stock.covers(&self.obsidian_collecting_robot_cost)
     }
     fn can_create_clay_robot(&self, stock: &Stock) -> bool {
-        stock >= &self.clay_collecting_robot_cost
+        stock.covers(&self.clay_collecting_robot_cost)
     }
     fn can_create_ore_robot(&self, stock: &Stock) -> bool {
-        stock >= &self.ore_collecting_robot_cost
+        stock.covers(&self.ore_collecting_robot_cost)
     }
 }
 
-fn take_number(input: &str) -> IResult<&str, u32> {
-    map_res(take_while(|c| is_digit(c as u8)), |num: &str| {
-        num.parse::<u32>()
-    })(input)
-}
-
 struct Robots {
     geode_cracking: u32,
     obsidian_collecting: u32,
@@ -300,7 +280,7 @@ impl Robots {
             Robot::ClayCollecting => self.clay_collecting += 1,
             Robot::OreCollecting => self.ore_collecting += 1,
         }
-        println!(
+        trace!(
             "The new {} robot is ready; you now have {} of them.",
             robot,
             match robot {
@@ -320,96 +300,77 @@ impl Robots {
         };
         *stock += mined;
         if mined.ore > 0 {
-            println!(
+            trace!(
                 "{} ore-collecting robot collect {} ore; you now have {} ore.",
-                self.ore_collecting, mined.ore, stock.ore
+                self.ore_collecting,
+                mined.ore,
+                stock.ore
             );
         }
         if mined.clay > 0 {
-            println!(
+            trace!(
                 "{} clay-collecting robot collect {} clay; you now have {} clay.",
-                self.clay_collecting, mined.clay, stock.clay
+                self.clay_collecting,
+                mined.clay,
+                stock.clay
             );
         }
         if mined.obsidian > 0 {
-            println!(
+            trace!(
                 "{} obsidian-collecting robot collect {} obsidian; you now have {} obsidian.",
-                self.obsidian_collecting, mined.obsidian, stock.obsidian
+                self.obsidian_collecting,
+                mined.obsidian,
+                stock.obsidian
             );
         }
         if mined.geode > 0 {
-            println!(
+            trace!(
                 "{} geode-cracking robot crack {} geode; you now have {} open geodes.",
-                self.geode_cracking, mined.geode, stock.geode
+                self.geode_cracking,
+                mined.geode,
+                stock.geode
             );
         }
     }
 }
 
-impl<'a> TryFrom<&'a str> for Blueprint {
-    type Error = nom::Err<nom::error::Error<&'a str>>;
-
-    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
-        let (input, id) =
-            nom::sequence::delimited(tag("Blueprint "), take_number, tag(": "))(input)?;
-        let (input, ore_collecting_robot_cost) = map_res(
-            preceded(
-                tag("Each ore robot costs "),
-                terminated(take_number, tag(" ore. ")),
-            ),
-            |cost| {
-                Ok::<_, Self::Error>(Stock {
-                    ore: cost,
-                    ..Default::default()
-                })
-            },
-        )(input)?;
-        let (input, clay_collecting_robot_cost) = map_res(
-            preceded(
-                tag("Each clay robot costs "),
-                terminated(take_number, tag(" ore. ")),
-            ),
-            |cost| {
-                Ok::<_, Self::Error>(Stock {
-                    ore: cost,
-                    ..Default::default()
-                })
-            },
-        )(input)?;
-        let (input, obsidian_collecting_robot_cost) = preceded(
-            tag("Each obsidian robot costs "),
-            map_res(
-                separated_pair(
-                    terminated(take_number, tag(" ore")),
-                    tag(" and "),
-                    terminated(take_number, tag(" clay. ")),
-                ),
-                |(ore_cost, clay_cost)| {
-                    Ok::<_, Self::Error>(Stock {
-                        ore: ore_cost,
-                        clay: clay_cost,
-                        ..Default::default()
-                    })
-                },
-            ),
-        )(input)?;
-        let (_, geode_cracking_robot_cost) = preceded(
-            tag("Each geode robot costs "),
-            map_res(
-                separated_pair(
-                    terminated(take_number, tag(" ore")),
-                    tag(" and "),
-                    terminated(take_number, tag(" obsidian.")),
-                ),
-                |(ore_cost, obsidian_cost)| {
-                    Ok::<_, Self::Error>(Stock {
-                        ore: ore_cost,
-                        obsidian: obsidian_cost,
-                        ..Default::default()
-                    })
-                },
-            ),
-        )(input)?;
+impl Blueprint {
+    fn parse(cursor: &mut Cursor) -> Result<Self, ParseError> {
+        cursor.tag("Blueprint ")?;
+        let id = cursor.number()?;
+        cursor.tag(": ")?;
+        cursor.tag("Each ore robot costs ")?;
+        let ore_collecting_robot_cost = Stock {
+            ore: cursor.number()?,
+            ..Default::default()
+        };
+        cursor.tag(" ore. ")?;
+        cursor.tag("Each clay robot costs ")?;
+        let clay_collecting_robot_cost = Stock {
+            ore: cursor.number()?,
+            ..Default::default()
+        };
+        cursor.tag(" ore. ")?;
+        cursor.tag("Each obsidian robot costs ")?;
+        let ore_cost = cursor.number()?;
+        cursor.tag(" ore and ")?;
+        let clay_cost = cursor.number()?;
+        let obsidian_collecting_robot_cost = Stock {
+            ore: ore_cost,
+            clay: clay_cost,
+            ..Default::default()
+        };
+        cursor.tag(" clay. ")?;
+        cursor.tag("Each geode robot costs ")?;
+        let ore_cost = cursor.number()?;
+        cursor.tag(" ore and ")?;
+        let obsidian_cost = cursor.number()?;
+        let geode_cracking_robot_cost = Stock {
+            ore: ore_cost,
+            obsidian: obsidian_cost,
+            ..Default::default()
+        };
+        cursor.tag(" obsidian.")?;
         Ok(Blueprint {
             id,
             ore_collecting_robot_cost,
@@ -430,28 +391,61 @@ impl<'a> TryFrom<&'a str> for Blueprint {
     }
 }
 
-fn main() {}
+pub struct Day19;
 
-fn part1(input: &str) -> u32 {
-    input
-        .lines()
-        .flat_map(|line| Blueprint::try_from(line))
-        .map(|blueprint| {
-            let mut factory = Factory::new(blueprint);
-            let mut robots = Robots::new();
-            for minute in 1..=24 {
-                println!("== Minute {minute} ==");
-                let new_robot = factory.create_robot(&robots);
-                robots.mine(&mut factory.stock);
-                match new_robot {
-                    Some(robot) => robots.add_robot(&robot),
-                    None => {}
+impl Solution for Day19 {
+    type Input<'a> = Vec<Blueprint>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::lines(input, Blueprint::parse)
+    }
+
+    fn part1(blueprints: &Self::Input<'_>) -> Answer {
+        blueprints
+            .iter()
+            .map(|blueprint| {
+                let mut factory = Factory::new(blueprint.clone());
+                let mut robots = Robots::new();
+                for minute in 1..=24 {
+                    trace!("== Minute {minute} ==");
+                    let new_robot = factory.create_robot(&robots);
+                    robots.mine(&mut factory.stock);
+                    if let Some(robot) = new_robot {
+                        robots.add_robot(&robot);
+                    }
                 }
-                println!("");
-            }
-            factory.blueprint.id * factory.stock.geode
+                debug!(
+                    "Blueprint {} opens {} geodes.",
+                    factory.blueprint.id, factory.stock.geode
+                );
+                factory.blueprint.id * factory.stock.geode
+            })
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part2(_blueprints: &Self::Input<'_>) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+/// Generates `size` blueprints, with costs in the ranges of the puzzle's.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (1..=size.max(1))
+        .map(|id| {
+            format!(
+                "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.",
+                id,
+                rng.range(2..=4),
+                rng.range(2..=4),
+                rng.range(2..=4),
+                rng.range(4..=20),
+                rng.range(2..=4),
+                rng.range(5..=20)
+            )
         })
-        .sum()
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 #[cfg(test)]
@@ -460,10 +454,10 @@ mod test {
 
     #[test]
     fn blueprint_parser() {
-        let blueprint = Blueprint::try_from("Blueprint 1: Each ore robot costs 3 ore. Each clay robot costs 4 ore. Each obsidian robot costs 2 ore and 20 clay. Each geode robot costs 4 ore and 7 obsidian.");
+        let blueprint = parse::lines("Blueprint 1: Each ore robot costs 3 ore. Each clay robot costs 4 ore. Each obsidian robot costs 2 ore and 20 clay. Each geode robot costs 4 ore and 7 obsidian.", Blueprint::parse);
         assert_eq!(
             blueprint,
-            Ok(Blueprint {
+            Ok(vec![Blueprint {
                 id: 1,
                 ore_collecting_robot_cost: Stock {
                     ore: 3,
@@ -489,22 +483,7 @@ mod test {
                     obsidian: 7,
                     geode: u32::MAX
                 }
-            })
+            }])
         );
     }
-
-    #[test]
-    fn part1() {
-        let input = "Blueprint 1: \
-                        Each ore robot costs 4 ore. \
-                        Each clay robot costs 2 ore. \
-                        Each obsidian robot costs 3 ore and 14 clay. \
-                        Each geode robot costs 2 ore and 7 obsidian.\n\
-                    Blueprint 2: \
-                        Each ore robot costs 2 ore. \
-                        Each clay robot costs 3 ore. \
-                        Each obsidian robot costs 3 ore and 8 clay. \
-                        Each geode robot costs 3 ore and 12 obsidian.";
-        println!("{:?}", super::part1(input));
-    }
 }
@@ -0,0 +1,567 @@
+use std::{collections::BTreeMap, fmt::Display, path::Path};
+
+use serde::Deserialize;
+
+use crate::{
+    generate::Rng,
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+    store::{self, StoreError},
+};
+
+/// A round of the strategy guide: the letter of the opponent's shape, and
+/// the letter of the second column, which is either the shape to respond
+/// with or the outcome to aim for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: char,
+    pub response: char,
+}
+
+/// A shape of a rule set, by its place among the shapes of the rule set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Shape(pub usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    /// The outcome a letter of the second column stands for in part 2.
+    pub fn from_letter(letter: char) -> Option<Self> {
+        match letter {
+            'X' => Some(Outcome::Lose),
+            'Y' => Some(Outcome::Draw),
+            'Z' => Some(Outcome::Win),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Outcome::Lose => "lose",
+            Outcome::Draw => "draw",
+            Outcome::Win => "win",
+        })
+    }
+}
+
+/// A rule set as written in its JSON config file.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RulesConfig {
+    pub shapes: Vec<ShapeConfig>,
+    /// The names of the shapes each shape beats.
+    pub beats: BTreeMap<String, Vec<String>>,
+    #[serde(default)]
+    pub scores: OutcomeScores,
+}
+
+/// A shape, the letters it is written with in either column of the guide,
+/// and the score for playing it.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ShapeConfig {
+    pub name: String,
+    pub opponent: char,
+    pub player: char,
+    pub score: u32,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OutcomeScores {
+    pub lose: u32,
+    pub draw: u32,
+    pub win: u32,
+}
+
+impl Default for OutcomeScores {
+    fn default() -> Self {
+        OutcomeScores {
+            lose: 0,
+            draw: 3,
+            win: 6,
+        }
+    }
+}
+
+/// The rules of a game of shapes, with the scores derived from which shape
+/// beats which.
+#[derive(Debug, Clone)]
+pub struct Rules {
+    shapes: Vec<ShapeConfig>,
+    /// Whether the first shape beats the second, by their places.
+    beats: Vec<Vec<bool>>,
+    scores: OutcomeScores,
+}
+
+impl Rules {
+    /// Rock, Paper and Scissors, as in the puzzle.
+    pub fn classic() -> Self {
+        let shape = |name: &str, opponent, player, score| ShapeConfig {
+            name: name.to_string(),
+            opponent,
+            player,
+            score,
+        };
+        let beats = |name: &str, beaten: &str| (name.to_string(), vec![beaten.to_string()]);
+        Rules::new(RulesConfig {
+            shapes: vec![
+                shape("rock", 'A', 'X', 1),
+                shape("paper", 'B', 'Y', 2),
+                shape("scissors", 'C', 'Z', 3),
+            ],
+            beats: BTreeMap::from([
+                beats("rock", "scissors"),
+                beats("paper", "rock"),
+                beats("scissors", "paper"),
+            ]),
+            scores: OutcomeScores::default(),
+        })
+        .expect("the classic rules are consistent")
+    }
+
+    /// Checks that the shapes and their letters are distinct and that of any
+    /// two different shapes exactly one beats the other.
+    pub fn new(config: RulesConfig) -> Result<Self, RulesError> {
+        let shapes = config.shapes;
+        for (i, shape) in shapes.iter().enumerate() {
+            for other in &shapes[..i] {
+                if other.name == shape.name {
+                    return Err(RulesError::DuplicateShape(shape.name.clone()));
+                }
+                for letter in [shape.opponent, shape.player] {
+                    if letter == other.opponent || letter == other.player {
+                        return Err(RulesError::DuplicateLetter(letter));
+                    }
+                }
+            }
+            if shape.opponent == shape.player {
+                return Err(RulesError::DuplicateLetter(shape.player));
+            }
+        }
+        let place = |name: &String| {
+            shapes
+                .iter()
+                .position(|shape| shape.name == *name)
+                .ok_or_else(|| RulesError::UnknownShape(name.clone()))
+        };
+        let mut beats = vec![vec![false; shapes.len()]; shapes.len()];
+        for (name, beaten) in &config.beats {
+            let winner = place(name)?;
+            for name in beaten {
+                beats[winner][place(name)?] = true;
+            }
+        }
+        for (a, first) in shapes.iter().enumerate() {
+            if beats[a][a] {
+                return Err(RulesError::BeatsItself(first.name.clone()));
+            }
+            for (b, second) in shapes.iter().enumerate().take(a) {
+                let pair = (second.name.clone(), first.name.clone());
+                match (beats[a][b], beats[b][a]) {
+                    (true, true) => return Err(RulesError::BeatEachOther(pair.0, pair.1)),
+                    (false, false) => return Err(RulesError::Undecided(pair.0, pair.1)),
+                    _ => {}
+                }
+            }
+        }
+        Ok(Rules {
+            shapes,
+            beats,
+            scores: config.scores,
+        })
+    }
+
+    /// Reads the rule set written as JSON at `path`.
+    pub fn load(path: &Path) -> Result<Self, RulesError> {
+        let config = store::load(path)
+            .map_err(RulesError::Store)?
+            .ok_or_else(|| RulesError::Missing(path.to_path_buf()))?;
+        Rules::new(config)
+    }
+
+    pub fn shapes(&self) -> impl Iterator<Item = Shape> {
+        (0..self.shapes.len()).map(Shape)
+    }
+
+    pub fn name(&self, shape: Shape) -> &str {
+        &self.shapes[shape.0].name
+    }
+
+    /// The shape the opponent's `letter` stands for.
+    pub fn opponent_shape(&self, letter: char) -> Option<Shape> {
+        self.shapes
+            .iter()
+            .position(|shape| shape.opponent == letter)
+            .map(Shape)
+    }
+
+    /// The shape `letter` stands for in the second column, in part 1.
+    pub fn player_shape(&self, letter: char) -> Option<Shape> {
+        self.shapes
+            .iter()
+            .position(|shape| shape.player == letter)
+            .map(Shape)
+    }
+
+    pub fn outcome(&self, player: Shape, opponent: Shape) -> Outcome {
+        if self.beats[player.0][opponent.0] {
+            Outcome::Win
+        } else if self.beats[opponent.0][player.0] {
+            Outcome::Lose
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    /// The score of a round: that of the shape played, plus that of the
+    /// outcome.
+    pub fn score(&self, player: Shape, opponent: Shape) -> u32 {
+        self.shapes[player.0].score
+            + match self.outcome(player, opponent) {
+                Outcome::Lose => self.scores.lose,
+                Outcome::Draw => self.scores.draw,
+                Outcome::Win => self.scores.win,
+            }
+    }
+
+    /// The best scoring shape with the given outcome against `opponent`,
+    /// the earliest one of those scoring as much.
+    pub fn response(&self, opponent: Shape, outcome: Outcome) -> Option<Shape> {
+        self.shapes()
+            .filter(|&player| self.outcome(player, opponent) == outcome)
+            .min_by_key(|&player| std::cmp::Reverse(self.score(player, opponent)))
+    }
+
+    /// Parses a strategy guide written with the letters of these rules,
+    /// where the second column holds shapes or outcomes.
+    pub fn parse_guide(&self, input: &str) -> Result<Vec<Round>, ParseError> {
+        let opponents: Vec<(char, char)> = self
+            .shapes
+            .iter()
+            .map(|shape| (shape.opponent, shape.opponent))
+            .collect();
+        let mut responses: Vec<(char, char)> = self
+            .shapes
+            .iter()
+            .map(|shape| (shape.player, shape.player))
+            .collect();
+        for letter in ['X', 'Y', 'Z'] {
+            if !responses.iter().any(|&(c, _)| c == letter) {
+                responses.push((letter, letter));
+            }
+        }
+        parse::lines(input, |line| {
+            let opponent = line.one_of(&opponents)?;
+            line.tag(" ")?;
+            let response = line.one_of(&responses)?;
+            Ok(Round { opponent, response })
+        })
+    }
+
+    /// The total score when the second column is the shape to play.
+    pub fn score_shapes(&self, rounds: &[Round]) -> Result<u32, RulesError> {
+        self.score_as(rounds, &Interpretation::Shapes(self.shapes().collect()))
+    }
+
+    /// Every way to read the second column: each assignment of the shapes
+    /// to the player letters, starting with the rules' own, and then the
+    /// outcomes.
+    pub fn interpretations(&self) -> Vec<Interpretation> {
+        let mut permutation: Vec<usize> = (0..self.shapes.len()).collect();
+        let mut interpretations = Vec::new();
+        loop {
+            interpretations.push(Interpretation::Shapes(
+                permutation.iter().map(|&shape| Shape(shape)).collect(),
+            ));
+            if !next_permutation(&mut permutation) {
+                break;
+            }
+        }
+        interpretations.push(Interpretation::Outcomes);
+        interpretations
+    }
+
+    /// The total score when the second column is read as `interpretation`.
+    pub fn score_as(
+        &self,
+        rounds: &[Round],
+        interpretation: &Interpretation,
+    ) -> Result<u32, RulesError> {
+        let Interpretation::Shapes(shapes) = interpretation else {
+            return self.score_outcomes(rounds);
+        };
+        rounds
+            .iter()
+            .map(|round| {
+                let letter = self
+                    .player_shape(round.response)
+                    .ok_or(RulesError::NotAShape(round.response))?;
+                Ok(self.score(shapes[letter.0], self.opponent(round)))
+            })
+            .sum()
+    }
+
+    /// What each letter of the second column stands for, as in
+    /// `X=rock Y=paper Z=scissors`.
+    pub fn describe(&self, interpretation: &Interpretation) -> String {
+        let meanings: Vec<String> = match interpretation {
+            Interpretation::Shapes(shapes) => self
+                .shapes
+                .iter()
+                .zip(shapes)
+                .map(|(letter, &shape)| format!("{}={}", letter.player, self.name(shape)))
+                .collect(),
+            Interpretation::Outcomes => ['X', 'Y', 'Z']
+                .into_iter()
+                .filter_map(|letter| Some(format!("{}={}", letter, Outcome::from_letter(letter)?)))
+                .collect(),
+        };
+        meanings.join(" ")
+    }
+
+    /// The total score when the second column is the outcome to aim for.
+    pub fn score_outcomes(&self, rounds: &[Round]) -> Result<u32, RulesError> {
+        rounds
+            .iter()
+            .map(|round| {
+                let outcome = Outcome::from_letter(round.response)
+                    .ok_or(RulesError::NotAnOutcome(round.response))?;
+                let opponent = self.opponent(round);
+                let player =
+                    self.response(opponent, outcome)
+                        .ok_or_else(|| RulesError::Unreachable {
+                            opponent: self.name(opponent).to_string(),
+                            outcome,
+                        })?;
+                Ok(self.score(player, opponent))
+            })
+            .sum()
+    }
+
+    fn opponent(&self, round: &Round) -> Shape {
+        self.opponent_shape(round.opponent)
+            .expect("rounds are parsed with the letters of the rules")
+    }
+}
+
+/// How to read the letters of the second column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Interpretation {
+    /// The shape each player letter stands for, the letters taken in the
+    /// order of the rules' shapes.
+    Shapes(Vec<Shape>),
+    /// `X`, `Y` and `Z` are the outcomes to aim for.
+    Outcomes,
+}
+
+/// Scores the guide under every interpretation of the second column, in
+/// the order of [`Rules::interpretations`].
+pub fn explore(rules: &Rules, rounds: &[Round]) -> Vec<(Interpretation, Result<u32, RulesError>)> {
+    rules
+        .interpretations()
+        .into_iter()
+        .map(|interpretation| {
+            let score = rules.score_as(rounds, &interpretation);
+            (interpretation, score)
+        })
+        .collect()
+}
+
+/// Rearranges `items` into the next permutation in lexicographic order, or
+/// returns false when they already are in the last one.
+fn next_permutation(items: &mut [usize]) -> bool {
+    let Some(pivot) = (1..items.len()).rev().find(|&i| items[i - 1] < items[i]) else {
+        return false;
+    };
+    let successor = (pivot..items.len())
+        .rev()
+        .find(|&i| items[i] > items[pivot - 1])
+        .expect("the item after the pivot is greater");
+    items.swap(pivot - 1, successor);
+    items[pivot..].reverse();
+    true
+}
+
+/// Why a rule set cannot be used.
+#[derive(Debug)]
+pub enum RulesError {
+    Missing(std::path::PathBuf),
+    Store(StoreError),
+    DuplicateShape(String),
+    DuplicateLetter(char),
+    UnknownShape(String),
+    BeatsItself(String),
+    BeatEachOther(String, String),
+    /// Neither shape beats the other.
+    Undecided(String, String),
+    NotAShape(char),
+    NotAnOutcome(char),
+    /// No shape gets the outcome against the opponent's shape.
+    Unreachable {
+        opponent: String,
+        outcome: Outcome,
+    },
+}
+
+impl Display for RulesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RulesError::Missing(path) => write!(f, "no rules at {}", path.display()),
+            RulesError::Store(err) => err.fmt(f),
+            RulesError::DuplicateShape(name) => write!(f, "{} is listed twice", name),
+            RulesError::DuplicateLetter(letter) => {
+                write!(f, "{:?} stands for more than one shape", letter)
+            }
+            RulesError::UnknownShape(name) => write!(f, "{} is not a listed shape", name),
+            RulesError::BeatsItself(name) => write!(f, "{} beats itself", name),
+            RulesError::BeatEachOther(a, b) => write!(f, "{} and {} beat each other", a, b),
+            RulesError::Undecided(a, b) => write!(f, "neither {} nor {} beats the other", a, b),
+            RulesError::NotAShape(letter) => write!(f, "{:?} is not a shape", letter),
+            RulesError::NotAnOutcome(letter) => write!(f, "{:?} is not an outcome", letter),
+            RulesError::Unreachable { opponent, outcome } => {
+                write!(f, "no shape can {} against {}", outcome, opponent)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RulesError {}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input<'a> = Vec<Round>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Rules::classic().parse_guide(input)
+    }
+
+    fn part1(rounds: &Self::Input<'_>) -> Answer {
+        Rules::classic()
+            .score_shapes(rounds)
+            .expect("X, Y and Z are classic shapes")
+            .into()
+    }
+
+    fn part2(rounds: &Self::Input<'_>) -> Answer {
+        Rules::classic()
+            .score_outcomes(rounds)
+            .expect("every classic outcome can be reached")
+            .into()
+    }
+}
+
+/// Generates a strategy guide of `size` rounds.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            format!(
+                "{} {}",
+                rng.pick(&['A', 'B', 'C']),
+                rng.pick(&['X', 'Y', 'Z'])
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rpsls() -> Rules {
+        Rules::new(serde_json::from_str(include_str!("../../rules/rpsls.json")).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn scores_follow_from_what_beats_what() {
+        let rules = Rules::classic();
+        let [rock, paper, scissors] = [Shape(0), Shape(1), Shape(2)];
+        assert_eq!(rules.outcome(paper, rock), Outcome::Win);
+        assert_eq!(rules.outcome(rock, paper), Outcome::Lose);
+        assert_eq!(rules.score(scissors, scissors), 6);
+        assert_eq!(rules.response(rock, Outcome::Lose), Some(scissors));
+    }
+
+    #[test]
+    fn other_rule_sets_are_loaded() {
+        let rules = rpsls();
+        let guide = rules.parse_guide("A V\nE X\nD Z").unwrap();
+        // Rock against rock is a draw.
+        assert_eq!(rules.score_shapes(&guide[..1]).unwrap(), 4);
+        // Both rock and scissors lose to spock and beat lizard, and
+        // scissors score more.
+        assert_eq!(rules.score_outcomes(&guide[1..]).unwrap(), 3 + 9);
+        assert!(matches!(
+            rules.score_outcomes(&guide),
+            Err(RulesError::NotAnOutcome('V'))
+        ));
+    }
+
+    #[test]
+    fn every_interpretation_is_scored() {
+        let rules = Rules::classic();
+        let fixture =
+            crate::fixture::Fixture::parse(include_str!("../../fixtures/day2/example.txt"))
+                .unwrap();
+        let rounds = Day2::parse(&fixture.input).unwrap();
+        let scores: Vec<(String, u32)> = explore(&rules, &rounds)
+            .into_iter()
+            .map(|(interpretation, score)| (rules.describe(&interpretation), score.unwrap()))
+            .collect();
+        assert_eq!(scores.len(), 7);
+        assert_eq!(scores[0], ("X=rock Y=paper Z=scissors".to_string(), 15));
+        assert_eq!(scores[6], ("X=lose Y=draw Z=win".to_string(), 12));
+        let matching: Vec<&str> = scores
+            .iter()
+            .filter(|(_, score)| *score == 15)
+            .map(|(description, _)| description.as_str())
+            .collect();
+        // The example alone cannot tell the intended reading apart.
+        assert_eq!(
+            matching,
+            [
+                "X=rock Y=paper Z=scissors",
+                "X=paper Y=rock Z=scissors",
+                "X=paper Y=scissors Z=rock",
+                "X=scissors Y=rock Z=paper"
+            ]
+        );
+        assert_eq!(rpsls().interpretations().len(), 121);
+    }
+
+    #[test]
+    fn inconsistent_rules_are_rejected() {
+        let config = |beats: &str| -> RulesConfig {
+            serde_json::from_str(&format!(
+                r#"{{"shapes": [
+                    {{"name": "rock", "opponent": "A", "player": "X", "score": 1}},
+                    {{"name": "paper", "opponent": "B", "player": "Y", "score": 2}}
+                ], "beats": {}}}"#,
+                beats
+            ))
+            .unwrap()
+        };
+        assert!(Rules::new(config(r#"{"paper": ["rock"]}"#)).is_ok());
+        assert!(matches!(
+            Rules::new(config(r#"{}"#)),
+            Err(RulesError::Undecided(..))
+        ));
+        assert!(matches!(
+            Rules::new(config(r#"{"paper": ["rock"], "rock": ["paper"]}"#)),
+            Err(RulesError::BeatEachOther(..))
+        ));
+        assert!(matches!(
+            Rules::new(config(r#"{"paper": ["stone"]}"#)),
+            Err(RulesError::UnknownShape(name)) if name == "stone"
+        ));
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    generate::Rng,
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+fn mix(original: &[i64], data: &mut Vec<usize>) {
+    for (original_index, &number) in original.iter().enumerate() {
+        let data_index = data
+            .iter()
+            .enumerate()
+            .find_map(|(index, original_ref_index)| {
+                (*original_ref_index == original_index).then_some(index)
+            })
+            .expect("Index exists in data");
+        let new_index = (data_index as i64 + number) % (data.len() - 1) as i64;
+        let new_index = match new_index {
+            ..=0 if number.is_negative() => data.len() as i64 + new_index - 1,
+            _ => new_index,
+        } as usize;
+        data.remove(data_index);
+        data.insert(new_index, original_index);
+    }
+}
+
+fn calculate_result(original: &[i64], data: &[usize]) -> i64 {
+    let original_zero_index = original
+        .iter()
+        .enumerate()
+        .find_map(|(index, &number)| (number == 0).then_some(index))
+        .expect("Index exists in data");
+    let base_index = data
+        .iter()
+        .enumerate()
+        .find_map(|(index, &original_index)| {
+            (original_index == original_zero_index).then_some(index)
+        })
+        .expect("index exists in data");
+    [1000, 2000, 3000]
+        .into_iter()
+        .map(|weight| (weight + base_index) % data.len())
+        .map(|index| data[index])
+        .map(|original_index| original[original_index])
+        .sum()
+}
+
+fn parse_input(input: &str) -> Result<Vec<i64>, ParseError> {
+    let numbers = parse::lines(input, |line| line.number::<i64>())?;
+    if !numbers.contains(&0) {
+        return Err(ParseError::at(input, &input[input.len()..], "a 0"));
+    }
+    Ok(numbers)
+}
+
+const PART_2_DECRYPTION_KEY: i64 = 811589153;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(original: &Self::Input<'_>) -> Answer {
+        let mut data = (0..original.len()).collect::<Vec<_>>();
+        mix(original, &mut data);
+        calculate_result(original, &data).into()
+    }
+
+    fn part2(numbers: &Self::Input<'_>) -> Answer {
+        let original = numbers
+            .iter()
+            .map(|number| number * PART_2_DECRYPTION_KEY)
+            .collect::<Vec<_>>();
+        let mut data = (0..original.len()).collect::<Vec<_>>();
+        for _ in 0..10 {
+            mix(&original, &mut data);
+        }
+        calculate_result(&original, &data).into()
+    }
+}
+
+/// Generates a file of `size` numbers, at least 1, exactly one of which is 0.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut numbers: Vec<i64> = (1..size.max(1))
+        .map(|_| loop {
+            let number = rng.range(-10_000..=10_000);
+            if number != 0 {
+                break number;
+            }
+        })
+        .collect();
+    numbers.insert(rng.below(numbers.len() + 1), 0);
+    numbers
+        .iter()
+        .map(i64::to_string)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,235 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    generate::Rng,
+    parse::{self, Cursor, ParseError},
+    solution::{Answer, Solution},
+};
+
+#[derive(Clone, Copy)]
+enum MathOperation {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+impl MathOperation {
+    fn calculate(&self, lhs: i64, rhs: i64) -> i64 {
+        match self {
+            MathOperation::Add => lhs + rhs,
+            MathOperation::Subtract => lhs - rhs,
+            MathOperation::Multiply => lhs * rhs,
+            MathOperation::Divide => lhs / rhs,
+        }
+    }
+}
+
+enum Job {
+    YellNumber(i64),
+    YellMathOperation(MathOperation, String, String),
+}
+
+pub struct Monkey {
+    name: String,
+    job: Job,
+}
+
+impl Monkey {
+    // root: pppw + sjmn
+    // or
+    // dbpl: 5
+    //
+    // Along with the monkey, returns where the names of the monkeys it listens
+    // to start.
+    fn parse<'a>(cursor: &mut Cursor<'a>) -> Result<(Self, Vec<Cursor<'a>>), ParseError> {
+        let monkey_name = cursor.word()?;
+        cursor.tag(": ")?;
+        let mut operands = Vec::new();
+        let job = match cursor.number() {
+            Ok(number) => Job::YellNumber(number),
+            Err(_) => {
+                operands.push(*cursor);
+                let monkey_name1 = cursor
+                    .word()
+                    .map_err(|_| cursor.error("a number or a monkey name"))?;
+                cursor.tag(" ")?;
+                let operator = cursor.one_of(&[
+                    ('+', MathOperation::Add),
+                    ('-', MathOperation::Subtract),
+                    ('*', MathOperation::Multiply),
+                    ('/', MathOperation::Divide),
+                ])?;
+                cursor.tag(" ")?;
+                operands.push(*cursor);
+                let monkey_name2 = cursor.word()?;
+                Job::YellMathOperation(operator, monkey_name1.to_string(), monkey_name2.to_string())
+            }
+        };
+        Ok((
+            Monkey {
+                name: monkey_name.to_string(),
+                job,
+            },
+            operands,
+        ))
+    }
+
+    fn yell(&self, monkeys: &HashMap<String, Monkey>) -> i64 {
+        match &self.job {
+            Job::YellNumber(num) => *num,
+            Job::YellMathOperation(op, lhs, rhs) => {
+                let lhs_result = monkeys
+                    .get(lhs)
+                    .expect("LHS monkey in hash map")
+                    .yell(monkeys);
+                let rhs_result = monkeys
+                    .get(rhs)
+                    .expect("RHS monkey in hash map")
+                    .yell(monkeys);
+                op.calculate(lhs_result, rhs_result)
+            }
+        }
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input<'a> = HashMap<String, Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let (monkeys, operands): (Vec<_>, Vec<_>) =
+            parse::lines(input, Monkey::parse)?.into_iter().unzip();
+        let monkeys: HashMap<_, _> = monkeys
+            .into_iter()
+            .map(|monkey| (monkey.name.clone(), monkey))
+            .collect();
+        for mut operand in operands.into_iter().flatten() {
+            let at = operand;
+            if !monkeys.contains_key(operand.word()?) {
+                return Err(at.error("the name of a listed monkey"));
+            }
+        }
+        if !monkeys.contains_key("root") {
+            return Err(ParseError::at(
+                input,
+                &input[input.len()..],
+                "a monkey named \"root\"",
+            ));
+        }
+        Ok(monkeys)
+    }
+
+    fn part1(monkeys: &Self::Input<'_>) -> Answer {
+        let root_monkey = monkeys.get("root").expect("Root monkey in hash map");
+        root_monkey.yell(monkeys).into()
+    }
+
+    fn part2(_monkeys: &Self::Input<'_>) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+/// Generates the jobs of `size` monkeys doing math operations, at least 1,
+/// and of the monkeys yelling the numbers they start from. As in the puzzle,
+/// every division is exact, every monkey yells a positive number, and `humn`
+/// yells one of the numbers.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut names = HashSet::from(["root".to_string(), "humn".to_string()]);
+    let mut new_name = |rng: &mut Rng| loop {
+        let name = rng.word(4);
+        if names.insert(name.clone()) {
+            break name;
+        }
+    };
+    // The jobs grow down from root: a monkey yelling a number gets to combine
+    // the numbers of two new monkeys into it instead.
+    let mut yelling = vec![("root".to_string(), rng.range(1..=1000))];
+    let mut operations = Vec::new();
+    for _ in 0..size.max(1) {
+        let (name, number) = yelling.swap_remove(rng.below(yelling.len()));
+        let (lhs, operator, rhs) = split_number(rng, number);
+        let (lhs_name, rhs_name) = (new_name(rng), new_name(rng));
+        operations.push((name, lhs_name.clone(), operator, rhs_name.clone()));
+        yelling.push((lhs_name, lhs));
+        yelling.push((rhs_name, rhs));
+    }
+    // The human replaces a monkey yelling a number, in the job of the monkey
+    // listening to it as well.
+    let human = yelling[rng.below(yelling.len())].0.clone();
+    let rename = |name: &String| {
+        if *name == human {
+            "humn".to_string()
+        } else {
+            name.clone()
+        }
+    };
+    let mut jobs: Vec<String> = operations
+        .iter()
+        .map(|(name, lhs, operator, rhs)| {
+            format!("{}: {} {} {}", name, rename(lhs), operator, rename(rhs))
+        })
+        .collect();
+    jobs.extend(
+        yelling
+            .iter()
+            .map(|(name, number)| format!("{}: {}", rename(name), number)),
+    );
+    rng.shuffle(&mut jobs);
+    jobs.join("\n")
+}
+
+/// Two positive numbers and the operator combining them into `number`,
+/// keeping the numbers below 2^40.
+fn split_number(rng: &mut Rng, number: i64) -> (i64, char, i64) {
+    const LIMIT: i64 = 1 << 40;
+    loop {
+        match rng.below(4) {
+            0 if number >= 2 => {
+                let lhs = rng.range(1..=number - 1);
+                return (lhs, '+', number - lhs);
+            }
+            1 if number < LIMIT - 1000 => {
+                let rhs = rng.range(1..=1000);
+                return (number + rhs, '-', rhs);
+            }
+            2 => {
+                let rhs = rng.range(2..=9);
+                if number % rhs == 0 {
+                    return (number / rhs, '*', rhs);
+                }
+            }
+            3 if number < LIMIT / 10 => {
+                let rhs = rng.range(2..=9);
+                return (number * rhs, '/', rhs);
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixture::Fixture;
+
+    fn example() -> String {
+        Fixture::parse(include_str!("../../fixtures/day21/example.txt"))
+            .unwrap()
+            .input
+    }
+
+    #[test]
+    fn parse() {
+        let example = example();
+        let monkeys = parse::lines(&example, Monkey::parse);
+        assert!(monkeys.is_ok());
+    }
+
+    #[test]
+    fn unknown_monkeys_are_rejected() {
+        let error = Day21::parse("root: abcd + efgh\nabcd: 5").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 14));
+        assert_eq!(error.expected, "the name of a listed monkey");
+    }
+}
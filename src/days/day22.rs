@@ -0,0 +1,267 @@
+use std::ops::Deref;
+
+use crate::{
+    generate::Rng,
+    geometry::{Direction, Point2, Turn},
+    grid::{Grid, Position},
+    parse::{Cursor, ParseError},
+    solution::{Answer, Solution},
+};
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input<'a> = (Map, Instructions);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok((Map::try_from(input)?, Instructions::try_from(input)?))
+    }
+
+    fn part1((map, instructions): &Self::Input<'_>) -> Answer {
+        let mut player = Player::new(map.find_starting_position());
+        for instruction in instructions.iter() {
+            player.follow_instruction(map, instruction);
+        }
+        let (column, row) = player.position;
+        (1000 * (row + 1) + 4 * (column + 1) + score(player.facing)).into()
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tile {
+    Void,
+    Open,
+    Wall,
+}
+
+#[derive(Debug)]
+pub struct Map {
+    tiles: Grid<Tile>,
+}
+
+#[derive(Debug)]
+pub enum Instruction {
+    Move(u32),
+    Turn(Turn),
+}
+
+#[derive(Debug)]
+pub struct Instructions(Vec<Instruction>);
+impl Deref for Instructions {
+    type Target = Vec<Instruction>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Splits the input into the map and the instructions sections.
+fn split_sections(input: &str) -> Result<(Cursor<'_>, Cursor<'_>), ParseError> {
+    let mut sections = Cursor::new(input).sections();
+    match (sections.next(), sections.next()) {
+        (Some(map), Some(instructions)) => Ok((map, instructions)),
+        _ => Err(ParseError::at(input, &input[input.len()..], "a blank line")),
+    }
+}
+
+impl TryFrom<&str> for Instructions {
+    type Error = ParseError;
+    fn try_from(input: &str) -> Result<Instructions, Self::Error> {
+        let (_, mut cursor) = split_sections(input)?;
+        let mut instructions = Vec::new();
+        while !cursor.is_at_end() {
+            let instruction = match cursor.number() {
+                Ok(move_quantity) => Instruction::Move(move_quantity),
+                Err(_) => Instruction::Turn(
+                    cursor.one_of(&[('R', Turn::Clockwise), ('L', Turn::CounterClockwise)])?,
+                ),
+            };
+            instructions.push(instruction);
+        }
+        Ok(Instructions(instructions))
+    }
+}
+
+fn score(facing: Direction) -> usize {
+    match facing {
+        Direction::East => 0,
+        Direction::South => 1,
+        Direction::West => 2,
+        Direction::North => 3,
+    }
+}
+
+struct Player {
+    position: Position,
+    facing: Direction,
+}
+
+impl Player {
+    fn new(position: Position) -> Self {
+        Player {
+            position,
+            facing: Direction::East,
+        }
+    }
+
+    fn follow_instruction(&mut self, map: &Map, instruction: &Instruction) {
+        match instruction {
+            Instruction::Turn(turn) => {
+                self.facing = self.facing.turn(*turn);
+            }
+            Instruction::Move(steps) => {
+                for _ in 0..*steps {
+                    let (x, y) = self.position;
+                    let next_position =
+                        Position::try_from(Point2::from(self.position) + self.facing.offset());
+                    let next_position = match next_position
+                        .ok()
+                        .and_then(|position| Some((position, map.tiles.get(position)?)))
+                    {
+                        Some((position, Tile::Open | Tile::Wall)) => position,
+                        _ => match self.facing {
+                            Direction::East => map.find_first_position_in_row(y),
+                            Direction::South => map.find_first_position_in_column(x),
+                            Direction::West => map.find_last_position_in_row(y),
+                            Direction::North => map.find_last_position_in_column(x),
+                        },
+                    };
+                    match map.tiles[next_position] {
+                        Tile::Open => self.position = next_position,
+                        Tile::Wall => break,
+                        Tile::Void => unreachable!(),
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl TryFrom<&str> for Map {
+    type Error = ParseError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (map, _) = split_sections(value)?;
+        let tiles = Grid::parse_padded(map, Tile::Void, |cursor| {
+            cursor.one_of(&[(' ', Tile::Void), ('.', Tile::Open), ('#', Tile::Wall)])
+        })?;
+        Ok(Map { tiles })
+    }
+}
+
+impl Map {
+    fn find_starting_position(&self) -> Position {
+        self.find_first_position_in_row(0)
+    }
+
+    fn find_first_position_in_row(&self, y: usize) -> Position {
+        let x = self
+            .tiles
+            .row(y)
+            .iter()
+            .position(|tile| *tile != Tile::Void)
+            .expect("first position in row");
+        (x, y)
+    }
+    fn find_last_position_in_row(&self, y: usize) -> Position {
+        let x = self
+            .tiles
+            .row(y)
+            .iter()
+            .rposition(|tile| *tile != Tile::Void)
+            .expect("last position in row");
+        (x, y)
+    }
+    fn find_first_position_in_column(&self, x: usize) -> Position {
+        let y = self
+            .tiles
+            .column(x)
+            .position(|tile| *tile != Tile::Void)
+            .expect("first position in column");
+        (x, y)
+    }
+    fn find_last_position_in_column(&self, x: usize) -> Position {
+        let y = self
+            .tiles
+            .column(x)
+            .rposition(|tile| *tile != Tile::Void)
+            .expect("last position in column");
+        (x, y)
+    }
+}
+
+/// Generates a board folded from six faces of `size` by `size` tiles, at
+/// least 2, laid out like the example's or the puzzle's, and a path of `size`
+/// times 40 moves. Walls cover a tenth of the tiles, but never the tile the
+/// path starts from.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const NETS: [&[&str]; 3] = [
+        &["  # ", "### ", "  ##"],
+        &[" ##", " # ", "## ", "#  "],
+        &["#   ", "####", "#   "],
+    ];
+    let side = size.max(2);
+    let net = rng.pick(&NETS);
+    let mut rows = Vec::new();
+    for faces in net.iter() {
+        for _ in 0..side {
+            let row: String = faces
+                .trim_end()
+                .chars()
+                .flat_map(|face| std::iter::repeat_n(face, side))
+                .map(|face| match face {
+                    ' ' => ' ',
+                    _ if rng.chance(0.1) => '#',
+                    _ => '.',
+                })
+                .collect();
+            rows.push(row);
+        }
+    }
+    let start = rows[0].find(|tile| tile != ' ').expect("faces have tiles");
+    rows[0].replace_range(start..start + 1, ".");
+    let moves: Vec<String> = (0..side * 40)
+        .map(|_| rng.range(1..=50).to_string())
+        .collect();
+    let path = moves
+        .iter()
+        .enumerate()
+        .map(|(index, steps)| match index {
+            0 => steps.clone(),
+            _ => format!("{}{}", rng.pick(&['R', 'L']), steps),
+        })
+        .collect::<String>();
+    format!("{}\n\n{}", rows.join("\n"), path)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixture::Fixture;
+
+    fn example() -> String {
+        Fixture::parse(include_str!("../../fixtures/day22/example.txt"))
+            .unwrap()
+            .input
+    }
+
+    #[test]
+    fn test_parse() {
+        let map = Map::try_from(example().as_str()).unwrap();
+        println!("{:?}", map);
+    }
+
+    #[test]
+    fn test_parse_instructions() {
+        let instructions = Instructions::try_from(example().as_str()).expect("instructions");
+        println!("{:?}", instructions);
+    }
+
+    #[test]
+    fn test_first_starting_position() {
+        let map = Map::try_from(example().as_str()).unwrap();
+        assert_eq!(map.find_starting_position(), (8, 0));
+    }
+}
@@ -0,0 +1,280 @@
+use std::{
+    array::IntoIter,
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    iter::Cycle,
+};
+
+use crate::{
+    generate::Rng,
+    geometry::{Direction, Direction8, Point2, Turn},
+    grid::{self, Position},
+    parse::{Cursor, ParseError},
+    solution::{Answer, Solution},
+    visualize::{Frame, Palette, Rgb, Simulation},
+};
+
+/// The three positions an elf looks at before proposing to move towards
+/// `direction`.
+fn get_proposed_positions(direction: Direction, elf: Point2) -> [Point2; 3] {
+    let ahead = Direction8::from(direction);
+    [
+        ahead.turn(Turn::CounterClockwise),
+        ahead,
+        ahead.turn(Turn::Clockwise),
+    ]
+    .map(|direction| elf + direction.offset())
+}
+
+const PART_1_ROUNDS: usize = 10;
+
+#[derive(Clone)]
+pub struct Grid {
+    elves: HashSet<Point2>,
+    proposed_direction_cycle: Cycle<IntoIter<Direction, 4>>,
+}
+
+impl Grid {
+    fn new(elves: HashSet<Point2>) -> Self {
+        Grid {
+            elves,
+            proposed_direction_cycle: [
+                Direction::North,
+                Direction::South,
+                Direction::West,
+                Direction::East,
+            ]
+            .into_iter()
+            .cycle(),
+        }
+    }
+
+    /// The top left and bottom right corners of the smallest rectangle
+    /// holding every elf.
+    fn edges(&self) -> (Point2, Point2) {
+        let top_left = self
+            .elves
+            .iter()
+            .fold(Point2::new(i64::MAX, i64::MAX), |cur, elf_pos| {
+                Point2::new(cur.x.min(elf_pos.x), cur.y.min(elf_pos.y))
+            });
+        let bottom_right = self
+            .elves
+            .iter()
+            .fold(Point2::new(i64::MIN, i64::MIN), |cur, elf_pos| {
+                Point2::new(cur.x.max(elf_pos.x), cur.y.max(elf_pos.y))
+            });
+        (top_left, bottom_right)
+    }
+
+    fn round(&mut self) -> usize {
+        let elves = self.elves.clone();
+        let elves_with_neighbors_iter = elves.iter().filter(|elf_position| {
+            elf_position
+                .neighbours8()
+                .iter()
+                .any(|pos| self.elves.contains(pos))
+        });
+        let proposed_moves = elves_with_neighbors_iter
+            .flat_map(|elf| -> Option<(&Point2, Point2)> {
+                for direction in self.proposed_direction_cycle.clone().take(4) {
+                    let proposed_positions = get_proposed_positions(direction, *elf);
+                    if proposed_positions
+                        .iter()
+                        .all(|pos| !self.elves.contains(pos))
+                    {
+                        return Some((elf, *elf + direction.offset()));
+                    }
+                }
+                None
+            })
+            .fold(
+                HashMap::<Point2, Vec<&Point2>>::new(),
+                |mut acc, (elf_pos, proposed_pos)| {
+                    acc.entry(proposed_pos)
+                        .and_modify(|elves| elves.push(elf_pos))
+                        .or_insert(vec![elf_pos]);
+                    acc
+                },
+            );
+        let moves_iter = proposed_moves
+            .into_iter()
+            .filter(|(_, elves)| elves.len() == 1)
+            .map(|(destination, elves)| (destination, elves[0]));
+        let mut moved = 0;
+        for (destination, elf_pos) in moves_iter {
+            self.elves.remove(elf_pos);
+            self.elves.insert(destination);
+            moved += 1;
+        }
+        self.proposed_direction_cycle.next();
+        moved
+    }
+
+    fn count_empty_tiles_covered(&self) -> usize {
+        let (top_left, bottom_right) = self.edges();
+        let area = (bottom_right.x - top_left.x + 1) * (bottom_right.y - top_left.y + 1);
+        area as usize - self.elves.len()
+    }
+
+    /// The smallest rectangle holding every elf.
+    fn frame(&self) -> Frame {
+        let (top_left, bottom_right) = self.edges();
+        let size = bottom_right - top_left + Point2::new(1, 1);
+        let mut tiles = grid::Grid::new(size.x as usize, size.y as usize, '.');
+        for &elf in &self.elves {
+            let position = Position::try_from(elf - top_left).expect("elves are within the edges");
+            tiles[position] = '#';
+        }
+        Frame {
+            origin: top_left,
+            tiles,
+        }
+    }
+}
+
+impl Display for Grid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.frame().tiles)
+    }
+}
+
+/// The elves spreading out one round at a time.
+struct Spreading {
+    grid: Grid,
+    /// The number of rounds to play, or `None` to play until no elf moves.
+    rounds_left: Option<usize>,
+    is_over: bool,
+}
+
+impl Display for Spreading {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+impl Simulation for Spreading {
+    fn frame(&self) -> Frame {
+        self.grid.frame()
+    }
+
+    fn step(&mut self) -> bool {
+        if self.is_over || self.rounds_left == Some(0) {
+            return false;
+        }
+        self.is_over = self.grid.round() == 0;
+        self.rounds_left = self.rounds_left.map(|rounds| rounds - 1);
+        true
+    }
+}
+
+/// Plays the 10 rounds of part 1, or every round until no elf moves for
+/// part 2.
+pub fn simulate(input: &str, part: u8) -> Result<Box<dyn Simulation>, ParseError> {
+    Ok(Box::new(Spreading {
+        grid: Grid::try_from(input)?,
+        rounds_left: (part == 1).then_some(PART_1_ROUNDS),
+        is_over: false,
+    }))
+}
+
+pub fn palette() -> Palette {
+    Palette::new(
+        Rgb(14, 30, 20),
+        &[('.', Rgb(14, 30, 20)), ('#', Rgb(96, 208, 112))],
+    )
+}
+
+impl TryFrom<&str> for Grid {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let tiles = grid::Grid::parse(Cursor::new(input), |cursor| {
+            cursor.one_of(&[('#', true), ('.', false)])
+        })?;
+        let elves = tiles
+            .iter()
+            .filter(|(_, &is_elf)| is_elf)
+            .map(|(position, _)| Point2::from(position))
+            .collect();
+        Ok(Grid::new(elves))
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input<'a> = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Grid::try_from(input)
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> Answer {
+        let mut grid = grid.clone();
+        for _ in 0..PART_1_ROUNDS {
+            grid.round();
+        }
+        grid.count_empty_tiles_covered().into()
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> Answer {
+        let mut grid = grid.clone();
+        let mut round = 1;
+        while grid.round() > 0 {
+            round += 1;
+        }
+        round.into()
+    }
+}
+
+/// Generates a scan of `size` by `size` tiles, with an elf on about half of
+/// them.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| if rng.chance(0.5) { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fixture::Fixture;
+
+    fn example() -> String {
+        Fixture::parse(include_str!("../../fixtures/day23/example.txt"))
+            .unwrap()
+            .input
+    }
+
+    #[test]
+    fn parse_grid() {
+        let grid = Grid::try_from(example().as_str()).unwrap();
+        println!("{}", grid);
+    }
+
+    #[test]
+    fn grid_edges() {
+        let grid = Grid::try_from(example().as_str()).unwrap();
+        let (top_left, bottom_right) = grid.edges();
+        assert_eq!(top_left, Point2::new(0, 0));
+        assert_eq!(bottom_right, Point2::new(6, 6));
+    }
+
+    #[test]
+    fn grid_round() {
+        let mut grid = Grid::try_from(example().as_str()).unwrap();
+        println!("{}", grid);
+        for _ in 0..10 {
+            println!("----------------\n");
+            grid.round();
+            println!("{}", grid);
+        }
+    }
+}
@@ -0,0 +1,339 @@
+use std::{
+    fmt::Display,
+    ops::{BitAnd, BitOr, RangeInclusive},
+};
+
+use log::debug;
+
+use crate::{
+    generate::Rng,
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+/// A set of item types as a mask, where bit `p` stands for the item type of
+/// priority `p`: `a` to `z` are 1 to 26, and `A` to `Z` 27 to 52.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Items(u64);
+
+impl Items {
+    /// Every item type.
+    pub const ALL: Items = Items(((1 << 52) - 1) << 1);
+
+    pub fn priority(item: char) -> Option<u32> {
+        match item {
+            'a'..='z' => Some(item as u32 - 'a' as u32 + 1),
+            'A'..='Z' => Some(item as u32 - 'A' as u32 + 27),
+            _ => None,
+        }
+    }
+
+    /// The item types among `items`, which are all letters.
+    fn from_letters(items: &str) -> Self {
+        Items(items.chars().fold(0, |mask, item| {
+            mask | 1 << Items::priority(item).expect("items are letters")
+        }))
+    }
+
+    pub fn len(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The priorities of the item types, lowest first.
+    pub fn priorities(self) -> impl Iterator<Item = u32> {
+        (1..=52).filter(move |priority| self.0 & 1 << priority != 0)
+    }
+}
+
+impl BitAnd for Items {
+    type Output = Items;
+
+    fn bitand(self, other: Items) -> Items {
+        Items(self.0 & other.0)
+    }
+}
+
+impl BitOr for Items {
+    type Output = Items;
+
+    fn bitor(self, other: Items) -> Items {
+        Items(self.0 | other.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rucksack {
+    pub left: Items,
+    pub right: Items,
+}
+
+impl Rucksack {
+    pub fn items(&self) -> Items {
+        self.left | self.right
+    }
+}
+
+/// The rucksacks, one per line, whatever groups they make.
+pub fn parse_rucksacks(input: &str) -> Result<Vec<Rucksack>, ParseError> {
+    parse::lines(input, |line| {
+        let contents = line.take_while(|c| c.is_ascii_alphabetic());
+        if !line.is_at_end() {
+            return Err(line.error("an item letter"));
+        }
+        if contents.len() % 2 != 0 {
+            return Err(line.error("an even number of items"));
+        }
+        let (left, right) = contents.split_at(contents.len() / 2);
+        Ok(Rucksack {
+            left: Items::from_letters(left),
+            right: Items::from_letters(right),
+        })
+    })
+}
+
+/// The number of elves in each group of the puzzle.
+const GROUP_SIZE: usize = 3;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input<'a> = Vec<Rucksack>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_rucksacks(input)
+    }
+
+    fn part1(rucksacks: &Self::Input<'_>) -> Answer {
+        rucksacks
+            .iter()
+            .flat_map(|rucksack| (rucksack.left & rucksack.right).priorities())
+            .sum::<u32>()
+            .into()
+    }
+
+    /// Unsolved when the rucksacks are not groups of three with one badge
+    /// each, which only matters to this part.
+    fn part2(rucksacks: &Self::Input<'_>) -> Answer {
+        match badge_priorities(rucksacks, GROUP_SIZE) {
+            Ok(sum) => sum.into(),
+            Err(err) => {
+                debug!("No badges: {}.", err);
+                Answer::Unsolved
+            }
+        }
+    }
+}
+
+/// The sum of the priorities of the badges of the groups of `group_size`
+/// elves, in a single pass over the rucksacks. The badge of a group is the
+/// one item type all of its rucksacks hold.
+pub fn badge_priorities(rucksacks: &[Rucksack], group_size: usize) -> Result<u32, BadgeError> {
+    if group_size == 0 {
+        return Err(BadgeError::EmptyGroups);
+    }
+    if !rucksacks.len().is_multiple_of(group_size) {
+        return Err(BadgeError::IncompleteGroup {
+            rucksacks: rucksacks.len(),
+            group_size,
+        });
+    }
+    let mut sum = 0;
+    let mut common = Items::ALL;
+    for (i, rucksack) in rucksacks.iter().enumerate() {
+        common = common & rucksack.items();
+        if (i + 1) % group_size != 0 {
+            continue;
+        }
+        let group = i / group_size + 1;
+        match common.len() {
+            1 => sum += common.priorities().sum::<u32>(),
+            0 => return Err(BadgeError::NoBadge { group }),
+            _ => return Err(BadgeError::SeveralBadges { group }),
+        }
+        common = Items::ALL;
+    }
+    Ok(sum)
+}
+
+/// Why the badges of groups of elves could not be found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BadgeError {
+    EmptyGroups,
+    /// The rucksacks do not split into whole groups.
+    IncompleteGroup {
+        rucksacks: usize,
+        group_size: usize,
+    },
+    /// The rucksacks of the group, counted from 1, have no item type in
+    /// common.
+    NoBadge {
+        group: usize,
+    },
+    SeveralBadges {
+        group: usize,
+    },
+}
+
+impl Display for BadgeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BadgeError::EmptyGroups => f.write_str("groups need at least one elf"),
+            BadgeError::IncompleteGroup {
+                rucksacks,
+                group_size,
+            } => match rucksacks {
+                1 => write!(f, "1 rucksack does not make groups of {}", group_size),
+                _ => write!(
+                    f,
+                    "{} rucksacks do not make groups of {}",
+                    rucksacks, group_size
+                ),
+            },
+            BadgeError::NoBadge { group } => write!(f, "group {} has no badge", group),
+            BadgeError::SeveralBadges { group } => {
+                write!(f, "group {} has more than one badge", group)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BadgeError {}
+
+/// Generates the rucksacks of `size` elves, rounded up to whole groups of
+/// three. As the puzzle promises, each rucksack has exactly one item type in
+/// both compartments, and each group exactly one item type in common.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    generate_groups(rng, size, GROUP_SIZE).expect("groups of three can be generated")
+}
+
+/// The group sizes rucksacks can be generated for: every elf needs at least
+/// three item types of their own besides the badge.
+pub const GENERATED_GROUP_SIZES: RangeInclusive<usize> = 2..=17;
+
+/// Generates the rucksacks of `size` elves in groups of `group_size`, or
+/// `None` if that size is not in `GENERATED_GROUP_SIZES`.
+pub fn generate_groups(rng: &mut Rng, size: usize, group_size: usize) -> Option<String> {
+    if !GENERATED_GROUP_SIZES.contains(&group_size) {
+        return None;
+    }
+    let item_types: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+    let mut rucksacks = Vec::new();
+    for _ in 0..size.max(1).div_ceil(group_size) {
+        let mut item_types = item_types.clone();
+        rng.shuffle(&mut item_types);
+        let badge = item_types.pop().expect("there are 52 item types");
+        // Every elf of the group packs item types of their own besides the
+        // badge.
+        for own in item_types
+            .chunks(item_types.len() / group_size)
+            .take(group_size)
+        {
+            let shared = if rng.chance(0.2) { badge } else { own[0] };
+            let (left_only, right_only) = own[1..].split_at(own.len() / 2);
+            let length = rng.below(12) + 4;
+            let mut left: Vec<char> = (1..length).map(|_| *rng.pick(left_only)).collect();
+            let mut right: Vec<char> = (1..length).map(|_| *rng.pick(right_only)).collect();
+            if shared != badge {
+                let compartment = if rng.chance(0.5) {
+                    &mut left
+                } else {
+                    &mut right
+                };
+                let i = rng.below(compartment.len());
+                compartment[i] = badge;
+            }
+            left.push(shared);
+            right.push(shared);
+            rng.shuffle(&mut left);
+            rng.shuffle(&mut right);
+            rucksacks.push(left.into_iter().chain(right).collect::<String>());
+        }
+    }
+    Some(rucksacks.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixture::Fixture;
+
+    #[test]
+    fn items_are_sets_of_priorities() {
+        let rucksack = parse_rucksacks("vJrwpWtwJgWrhcsFMMfFFhFp").unwrap()[0];
+        let shared = rucksack.left & rucksack.right;
+        assert_eq!(shared.priorities().collect::<Vec<_>>(), [16]);
+        assert_eq!(rucksack.items().len(), 14);
+        assert_eq!(Items::ALL.len(), 52);
+        assert_eq!(Items::priority('Z'), Some(52));
+    }
+
+    #[test]
+    fn badges_are_found_for_any_group_size() {
+        let fixture = Fixture::parse(include_str!("../../fixtures/day3/example.txt")).unwrap();
+        let rucksacks = parse_rucksacks(&fixture.input).unwrap();
+        assert_eq!(badge_priorities(&rucksacks, 3), Ok(18 + 52));
+
+        let rucksacks = parse_rucksacks("abcd\ndxyz\nABCD\nDEFG\nazAY\nbzBX").unwrap();
+        assert_eq!(badge_priorities(&rucksacks, 2), Ok(4 + 30 + 26));
+        assert_eq!(
+            badge_priorities(&rucksacks[..4], 4),
+            Err(BadgeError::NoBadge { group: 1 })
+        );
+        let rucksacks = parse_rucksacks("abcd\nadef\nagha\naijk").unwrap();
+        assert_eq!(badge_priorities(&rucksacks, 4), Ok(1));
+
+        for group_size in [2, 4, 7, 17] {
+            let input = generate_groups(&mut Rng::new(1), 28, group_size).unwrap();
+            let rucksacks = parse_rucksacks(&input).unwrap();
+            assert!(badge_priorities(&rucksacks, group_size).is_ok());
+        }
+        assert_eq!(generate_groups(&mut Rng::new(1), 28, 1), None);
+        assert_eq!(generate_groups(&mut Rng::new(1), 28, 18), None);
+    }
+
+    #[test]
+    fn groups_without_one_badge_are_rejected() {
+        let rucksacks = parse_rucksacks("ab\nca\ncd\nef").unwrap();
+        assert_eq!(
+            badge_priorities(&rucksacks, 2),
+            Err(BadgeError::NoBadge { group: 2 })
+        );
+        assert_eq!(
+            badge_priorities(&rucksacks, 3),
+            Err(BadgeError::IncompleteGroup {
+                rucksacks: 4,
+                group_size: 3
+            })
+        );
+        assert_eq!(
+            badge_priorities(&rucksacks, 1),
+            Err(BadgeError::SeveralBadges { group: 1 })
+        );
+        assert_eq!(
+            badge_priorities(&rucksacks, 0),
+            Err(BadgeError::EmptyGroups)
+        );
+
+        assert_eq!(
+            BadgeError::IncompleteGroup {
+                rucksacks: 1,
+                group_size: 3
+            }
+            .to_string(),
+            "1 rucksack does not make groups of 3"
+        );
+    }
+
+    #[test]
+    fn only_part_2_needs_groups_of_three() {
+        let rucksacks = Day3::parse("abca\nadef\nagha\naijk").unwrap();
+        assert_eq!(Day3::part1(&rucksacks), Answer::Number(2));
+        assert_eq!(Day3::part2(&rucksacks), Answer::Unsolved);
+        let rucksacks = Day3::parse("ab\nca\nad\nef\ngh\nij").unwrap();
+        assert_eq!(Day3::part2(&rucksacks), Answer::Unsolved);
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{
+    generate::Rng,
+    parse::{self, Cursor, ParseError},
+    solution::{Answer, Solution},
+};
+
+#[derive(Debug)]
+pub struct Assignment {
+    section_start: u32,
+    section_end: u32,
+}
+
+impl Assignment {
+    fn parse(cursor: &mut Cursor) -> Result<Self, ParseError> {
+        let left = cursor.number()?;
+        cursor.tag("-")?;
+        let right = cursor.number()?;
+        Ok(Assignment {
+            section_start: left,
+            section_end: right,
+        })
+    }
+
+    fn contains(&self, other: &Assignment) -> bool {
+        self.section_start <= other.section_start && self.section_end >= other.section_end
+    }
+    fn overlaps(&self, other: &Assignment) -> bool {
+        self.contains(other)
+            || other.contains(self)
+            || (self.section_start <= other.section_start
+                && self.section_end >= other.section_start)
+            || (self.section_start <= other.section_end && self.section_end >= other.section_end)
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input<'a> = Vec<(Assignment, Assignment)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::lines(input, |line| {
+            let first_elf = Assignment::parse(line)?;
+            line.tag(",")?;
+            let second_elf = Assignment::parse(line)?;
+            Ok((first_elf, second_elf))
+        })
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        input
+            .iter()
+            .filter(|(first_elf_assignment, second_elf_assignment)| {
+                first_elf_assignment.contains(second_elf_assignment)
+                    || second_elf_assignment.contains(first_elf_assignment)
+            })
+            .map(|_| 1)
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        input
+            .iter()
+            .filter(|(first_elf_assignment, second_elf_assignment)| {
+                first_elf_assignment.overlaps(second_elf_assignment)
+            })
+            .map(|_| 1)
+            .sum::<u32>()
+            .into()
+    }
+}
+
+/// Generates `size` pairs of assignments within sections 1 to 99.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let assignment = |rng: &mut Rng| {
+        let first = rng.range(1..=99);
+        format!("{}-{}", first, rng.range(first..=99))
+    };
+    (0..size.max(1))
+        .map(|_| format!("{},{}", assignment(rng), assignment(rng)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
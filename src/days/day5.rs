@@ -0,0 +1,227 @@
+use crate::{
+    generate::Rng,
+    parse::{Cursor, ParseError},
+    solution::{Answer, Solution},
+};
+
+#[derive(Clone, Default)]
+pub struct Stack {
+    crates: Vec<char>,
+}
+#[derive(Clone)]
+pub struct Ship {
+    stacks: Vec<Stack>,
+}
+
+impl Ship {
+    /// Moves `count` crates one at a time, or returns false if the stack
+    /// holds fewer than that.
+    fn move_crates(&mut self, count: u32, from: usize, to: usize) -> bool {
+        let source_index = from - 1;
+        let destination_index = to - 1;
+        let source_stack = self.stacks.get_mut(source_index).unwrap();
+        let Some(at) = source_stack.crates.len().checked_sub(count as usize) else {
+            return false;
+        };
+        let mut crates_to_move = source_stack.crates.split_off(at);
+        crates_to_move.reverse();
+        let destination_stack = self.stacks.get_mut(destination_index).unwrap();
+        destination_stack.crates.append(&mut crates_to_move);
+        true
+    }
+
+    /// Moves `count` crates all at once, or returns false if the stack
+    /// holds fewer than that.
+    fn move_crates_at_once(&mut self, count: u32, from: usize, to: usize) -> bool {
+        let source_index = from - 1;
+        let destination_index = to - 1;
+        let source_stack = self.stacks.get_mut(source_index).unwrap();
+        let Some(at) = source_stack.crates.len().checked_sub(count as usize) else {
+            return false;
+        };
+        let mut crates_to_move = source_stack.crates.split_off(at);
+        let destination_stack = self.stacks.get_mut(destination_index).unwrap();
+        destination_stack.crates.append(&mut crates_to_move);
+        true
+    }
+
+    fn top_crates(&self) -> String {
+        self.stacks
+            .iter()
+            .flat_map(|stack| stack.crates.last())
+            .collect()
+    }
+}
+
+pub struct Move {
+    count: u32,
+    from: usize,
+    to: usize,
+}
+
+//     [D]
+// [N] [C]
+// [Z] [M] [P]
+//  1   2   3
+fn parse_ship(drawing: Cursor) -> Result<Ship, ParseError> {
+    let mut rows: Vec<Cursor> = drawing.lines().collect();
+    let Some(mut labels) = rows.pop() else {
+        return Err(drawing.error("a drawing of the stacks"));
+    };
+    let mut number_of_stacks = 0;
+    loop {
+        labels.take_while(|c| c == ' ');
+        if labels.is_at_end() {
+            break;
+        }
+        let at = labels;
+        if labels.number::<usize>()? != number_of_stacks + 1 {
+            return Err(at.error(format!("stack number {}", number_of_stacks + 1)));
+        }
+        number_of_stacks += 1;
+    }
+    let mut ship = Ship {
+        stacks: vec![Stack::default(); number_of_stacks],
+    };
+    for mut row in rows {
+        for (stack_index, stack) in ship.stacks.iter_mut().enumerate() {
+            if row.is_at_end() {
+                break;
+            }
+            if stack_index > 0 {
+                row.tag(" ")?;
+            }
+            if row.eat("[") {
+                stack
+                    .crates
+                    .push(row.satisfy("a crate letter", char::is_alphabetic)?);
+                row.tag("]")?;
+            } else {
+                row.tag("   ")?;
+            }
+        }
+        row.end()?;
+    }
+    for stack in ship.stacks.iter_mut() {
+        stack.crates.reverse();
+    }
+    Ok(ship)
+}
+
+// move 1 from 2 to 1
+fn parse_move(line: &mut Cursor, number_of_stacks: usize) -> Result<Move, ParseError> {
+    let stack_number = |line: &mut Cursor| {
+        let at = *line;
+        match line.number::<usize>()? {
+            number @ 1.. if number <= number_of_stacks => Ok(number),
+            _ => Err(at.error(format!("a stack between 1 and {}", number_of_stacks))),
+        }
+    };
+    line.tag("move ")?;
+    let count = line.number()?;
+    line.tag(" from ")?;
+    let from = stack_number(line)?;
+    line.tag(" to ")?;
+    let to = stack_number(line)?;
+    Ok(Move { count, from, to })
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input<'a> = (Ship, Vec<Move>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let cursor = Cursor::new(input);
+        let mut sections = cursor.sections();
+        let ship = parse_ship(sections.next().expect("split yields a first section"))?;
+        let Some(instructions) = sections.next() else {
+            return Err(ParseError::at(input, &input[input.len()..], "a blank line"));
+        };
+        let moves = instructions.parse_lines(|line| parse_move(line, ship.stacks.len()))?;
+        Ok((ship, moves))
+    }
+
+    fn part1((ship, moves): &Self::Input<'_>) -> Answer {
+        let mut ship = ship.clone();
+        for Move { count, from, to } in moves {
+            if !ship.move_crates(*count, *from, *to) {
+                return Answer::Unsolved;
+            }
+        }
+        ship.top_crates().into()
+    }
+
+    fn part2((ship, moves): &Self::Input<'_>) -> Answer {
+        let mut ship = ship.clone();
+        for Move { count, from, to } in moves {
+            if !ship.move_crates_at_once(*count, *from, *to) {
+                return Answer::Unsolved;
+            }
+        }
+        ship.top_crates().into()
+    }
+}
+
+/// Generates a drawing of nine stacks and `size` moves, none of which takes
+/// more crates than its stack holds.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const STACKS: usize = 9;
+    let crate_letter = |rng: &mut Rng| (b'A' + rng.below(26) as u8) as char;
+    let mut stacks: Vec<Vec<char>> = (0..STACKS)
+        .map(|_| (0..rng.below(9)).map(|_| crate_letter(rng)).collect())
+        .collect();
+    if stacks.iter().all(Vec::is_empty) {
+        stacks[0].push(crate_letter(rng));
+    }
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut lines: Vec<String> = (0..height)
+        .rev()
+        .map(|level| {
+            stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(letter) => format!("[{}]", letter),
+                    None => "   ".to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect();
+    lines.push(
+        (1..=STACKS)
+            .map(|number| format!(" {} ", number))
+            .collect::<Vec<_>>()
+            .join(" "),
+    );
+    lines.push(String::new());
+    for _ in 0..size.max(1) {
+        let from = loop {
+            let from = rng.below(STACKS);
+            if !stacks[from].is_empty() {
+                break from;
+            }
+        };
+        let to = (from + 1 + rng.below(STACKS - 1)) % STACKS;
+        let count = 1 + rng.below(stacks[from].len().min(12));
+        let at = stacks[from].len() - count;
+        let moved: Vec<char> = stacks[from].drain(at..).collect();
+        stacks[to].extend(moved);
+        lines.push(format!("move {} from {} to {}", count, from + 1, to + 1));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn moves_need_enough_crates() {
+        let input = Day5::parse("[A]\n 1  2 \n\nmove 2 from 1 to 2").unwrap();
+        assert_eq!(Day5::part1(&input), Answer::Unsolved);
+        assert_eq!(Day5::part2(&input), Answer::Unsolved);
+        let input = Day5::parse("[A]\n 1  2 \n\nmove 1 from 1 to 2").unwrap();
+        assert_eq!(Day5::part1(&input), Answer::from("A"));
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{
+    generate::Rng,
+    parse::{Cursor, ParseError},
+    solution::{Answer, Solution},
+};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut cursor = Cursor::new(input);
+        let datastream = cursor.take_while(|c| c.is_ascii_lowercase());
+        if !cursor.is_at_end() {
+            return Err(cursor.error("a lowercase letter"));
+        }
+        Ok(datastream)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        process_input::<4>(input).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        process_input::<14>(input).into()
+    }
+}
+
+fn process_input<const NUM_OF_DISTINCT_CHARS: usize>(input: &str) -> usize {
+    let mut last_n_chars = ['.'; NUM_OF_DISTINCT_CHARS];
+    'input_loop: for (index, char) in input.chars().enumerate() {
+        let sub_index = index % NUM_OF_DISTINCT_CHARS;
+        last_n_chars[sub_index] = char;
+        for (char_index, char_to_compare) in last_n_chars.iter().enumerate() {
+            if !char_to_compare.is_alphabetic() {
+                continue 'input_loop;
+            }
+            for other_char in last_n_chars.iter().skip(char_index + 1) {
+                if char_to_compare == other_char {
+                    continue 'input_loop;
+                }
+            }
+        }
+        return index + 1;
+    }
+    0
+}
+
+/// Generates a datastream of `size` letters, at least 14. It draws on only
+/// eight letters but for one start-of-message marker placed at random.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const MARKER: usize = 14;
+    let size = size.max(MARKER);
+    let mut datastream: Vec<u8> = (0..size).map(|_| b'a' + rng.below(8) as u8).collect();
+    let mut marker: Vec<u8> = (b'a'..=b'z').collect();
+    rng.shuffle(&mut marker);
+    let at = rng.below(size - MARKER + 1);
+    datastream[at..at + MARKER].copy_from_slice(&marker[..MARKER]);
+    String::from_utf8(datastream).expect("letters are ASCII")
+}
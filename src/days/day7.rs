@@ -0,0 +1,273 @@
+use std::{
+    cell::RefCell,
+    collections::{hash_map::Entry, HashMap, HashSet},
+    rc::Rc,
+};
+
+use crate::{
+    generate::Rng,
+    parse::{Cursor, ParseError},
+    solution::{Answer, Solution},
+};
+
+#[derive(Debug, Default)]
+pub struct Directory<'a> {
+    total_size: u32,
+    directories: HashMap<&'a str, Rc<RefCell<Directory<'a>>>>,
+    files: HashMap<&'a str, u32>,
+}
+
+#[derive(Debug)]
+struct Pwd<'a> {
+    path: Vec<Rc<RefCell<Directory<'a>>>>,
+}
+
+impl<'a> Pwd<'a> {
+    fn new(root: Rc<RefCell<Directory<'a>>>) -> Self {
+        Pwd {
+            path: vec![root.clone()],
+        }
+    }
+
+    fn navigate_to_root(&mut self) {
+        let root = self.path[0].clone();
+        self.path = vec![root];
+    }
+
+    /// Moves to the parent directory, unless this is the root.
+    fn navigate_up(&mut self) -> bool {
+        if self.path.len() == 1 {
+            return false;
+        }
+        self.path.pop();
+        true
+    }
+
+    fn navigate_into_dir(&mut self, sub_directory_name: &'a str) {
+        let sub_directory = self.ensure_directory(sub_directory_name);
+        self.path.push(sub_directory);
+    }
+
+    fn ensure_directory(&mut self, sub_directory_name: &'a str) -> Rc<RefCell<Directory<'a>>> {
+        let current_directory = self.path.last().expect("pwd should not be empty, ever");
+        let directories = &mut current_directory.borrow_mut().directories;
+        directories.entry(sub_directory_name).or_default().clone()
+    }
+
+    fn ensure_file(&mut self, file_name: &'a str, file_size: u32) {
+        let size_to_add_to_dirs = {
+            let current_directory = self.path.last().expect("pwd should not be empty, ever");
+            let files = &mut current_directory.borrow_mut().files;
+            match files.entry(file_name) {
+                Entry::Vacant(entry) => *entry.insert(file_size),
+                _ => 0,
+            }
+        };
+        for dir in self.path.iter() {
+            dir.borrow_mut().total_size += size_to_add_to_dirs;
+        }
+    }
+
+    fn process_input(&mut self, input: &'a str) -> Result<(), ParseError> {
+        for mut line in Cursor::new(input).lines() {
+            if line.eat("$ ") {
+                if line.eat("cd ") {
+                    let at = line;
+                    match line.take_line() {
+                        "/" => {
+                            self.navigate_to_root();
+                        }
+                        ".." => {
+                            if !self.navigate_up() {
+                                return Err(at.error("a directory below /"));
+                            }
+                        }
+                        "" => return Err(line.error("a directory name")),
+                        sub_directory_name => {
+                            self.navigate_into_dir(sub_directory_name);
+                        }
+                    }
+                } else {
+                    line.tag("ls")?;
+                }
+            } else if line.eat("dir ") {
+                match line.take_line() {
+                    "" => return Err(line.error("a directory name")),
+                    dir_name => {
+                        self.ensure_directory(dir_name);
+                    }
+                }
+            } else {
+                let file_size = line
+                    .number()
+                    .map_err(|_| line.error("a command, a directory or a file size"))?;
+                line.tag(" ")?;
+                match line.take_line() {
+                    "" => return Err(line.error("a file name")),
+                    file_name => self.ensure_file(file_name, file_size),
+                }
+            }
+            line.end()?;
+        }
+        Ok(())
+    }
+}
+
+const TOTAL_DISK_SIZE: u32 = 70_000_000;
+const NEEDED_UNUSED_SPACE: u32 = 30_000_000;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input<'a> = Rc<RefCell<Directory<'a>>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let root = Rc::new(RefCell::new(Directory::default()));
+
+        let mut pwd = Pwd::new(root.clone());
+        pwd.process_input(input)?;
+
+        Ok(root)
+    }
+
+    fn part1(root: &Self::Input<'_>) -> Answer {
+        fn process_dir(dir: &Directory, sum_of_dirs_with_size_lte_100k: &mut u32) {
+            if dir.total_size <= 100_000 {
+                *sum_of_dirs_with_size_lte_100k += dir.total_size;
+            }
+            for (_, sub_dir) in dir.directories.iter() {
+                process_dir(&sub_dir.borrow(), sum_of_dirs_with_size_lte_100k);
+            }
+        }
+
+        let mut sum_of_dirs_with_size_lte_100k = 0;
+        process_dir(&root.borrow(), &mut sum_of_dirs_with_size_lte_100k);
+
+        sum_of_dirs_with_size_lte_100k.into()
+    }
+
+    fn part2(root: &Self::Input<'_>) -> Answer {
+        // A listing may fill more than the disk, or leave enough space
+        // already, and then there is no directory to pick.
+        let Some(free_space) = TOTAL_DISK_SIZE.checked_sub(root.borrow().total_size) else {
+            return Answer::Unsolved;
+        };
+        let Some(missing_space) = NEEDED_UNUSED_SPACE.checked_sub(free_space) else {
+            return Answer::Unsolved;
+        };
+
+        fn process_dir(dir: &Directory, missing_space: u32, valid_sizes: &mut Vec<u32>) {
+            if dir.total_size >= missing_space {
+                valid_sizes.push(dir.total_size);
+            }
+            for (_, sub_dir) in dir.directories.iter() {
+                process_dir(&sub_dir.borrow(), missing_space, valid_sizes);
+            }
+        }
+
+        let mut valid_sizes = Vec::new();
+        process_dir(&root.borrow(), missing_space, &mut valid_sizes);
+
+        match valid_sizes.into_iter().min() {
+            Some(size) => size.into(),
+            None => Answer::Unsolved,
+        }
+    }
+}
+
+/// Generates the terminal output of exploring `size` directories. Their
+/// files fill between 41 and 69 million of the disk's 70 million, so some
+/// directory always has to be deleted and there is always one big enough.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let directories = size.max(1);
+    let mut children = vec![Vec::new(); directories];
+    for directory in 1..directories {
+        children[rng.below(directory)].push(directory);
+    }
+    // Cubed weights make a few files far bigger than the rest.
+    let mut weights: Vec<Vec<u64>> = (0..directories)
+        .map(|_| {
+            (0..rng.below(5))
+                .map(|_| (rng.below(1000) as u64 + 1).pow(3))
+                .collect()
+        })
+        .collect();
+    if weights.iter().all(Vec::is_empty) {
+        weights[0].push(1);
+    }
+    let total_weight: u64 = weights.iter().flatten().sum();
+    let used = rng.range(41_000_000..=69_000_000) as u64;
+    let file_sizes: Vec<Vec<u64>> = weights
+        .iter()
+        .map(|weights| {
+            weights
+                .iter()
+                .map(|weight| (weight * used / total_weight).max(1))
+                .collect()
+        })
+        .collect();
+    let mut lines = vec!["$ cd /".to_string()];
+    list_directory(rng, 0, &children, &file_sizes, &mut lines);
+    lines.join("\n")
+}
+
+/// Lists `directory` and then explores its subdirectories one by one.
+fn list_directory(
+    rng: &mut Rng,
+    directory: usize,
+    children: &[Vec<usize>],
+    file_sizes: &[Vec<u64>],
+    lines: &mut Vec<String>,
+) {
+    let mut names = HashSet::new();
+    let mut new_name = |rng: &mut Rng, extension: bool| loop {
+        let length = rng.below(8) + 1;
+        let mut name = rng.word(length);
+        if extension && rng.chance(0.5) {
+            name = format!("{}.{}", name, rng.word(3));
+        }
+        if names.insert(name.clone()) {
+            break name;
+        }
+    };
+    let subdirectories: Vec<(usize, String)> = children[directory]
+        .iter()
+        .map(|&child| (child, new_name(rng, false)))
+        .collect();
+    let mut listing: Vec<String> = subdirectories
+        .iter()
+        .map(|(_, name)| format!("dir {}", name))
+        .chain(
+            file_sizes[directory]
+                .iter()
+                .map(|size| format!("{} {}", size, new_name(rng, true))),
+        )
+        .collect();
+    rng.shuffle(&mut listing);
+    lines.push("$ ls".to_string());
+    lines.extend(listing);
+    for (child, name) in subdirectories {
+        lines.push(format!("$ cd {}", name));
+        list_directory(rng, child, children, file_sizes, lines);
+        lines.push("$ cd ..".to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_root_has_no_parent() {
+        let error = Day7::parse("$ cd /\n$ cd ..\n$ ls").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 6));
+        assert_eq!(error.expected, "a directory below /");
+    }
+
+    #[test]
+    fn small_listings_need_no_deletion() {
+        let root = Day7::parse("$ cd /\n$ ls\n100 a.txt").unwrap();
+        assert_eq!(Day7::part1(&root), Answer::Number(100));
+        assert_eq!(Day7::part2(&root), Answer::Unsolved);
+    }
+}
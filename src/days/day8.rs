@@ -0,0 +1,77 @@
+use crate::{
+    generate::Rng,
+    grid::{Grid, Position},
+    parse::{Cursor, ParseError},
+    solution::{Answer, Solution},
+};
+
+/// The trees between `(x, y)` and each edge, nearest first, clockwise from
+/// the ones above.
+fn lines_of_sight(trees: &Grid<u32>, (x, y): Position) -> [Vec<u32>; 4] {
+    [
+        trees.column(x).take(y).rev().copied().collect(),
+        trees.row(y)[x + 1..].to_vec(),
+        trees.column(x).skip(y + 1).copied().collect(),
+        trees.row(y)[..x].iter().rev().copied().collect(),
+    ]
+}
+
+fn count_visible_trees(line_of_sight: &[u32], tree_height: u32) -> u32 {
+    let mut valid_tree_count = 0;
+    for &other_tree_height in line_of_sight {
+        valid_tree_count += 1;
+        if other_tree_height >= tree_height {
+            break;
+        }
+    }
+    valid_tree_count
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input<'a> = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Grid::parse(Cursor::new(input), |cursor| cursor.digit())
+    }
+
+    fn part1(trees: &Self::Input<'_>) -> Answer {
+        trees
+            .iter()
+            .filter(|&(position, &tree_height)| {
+                lines_of_sight(trees, position)
+                    .iter()
+                    .any(|line_of_sight| line_of_sight.iter().all(|&other| other < tree_height))
+            })
+            .count()
+            .into()
+    }
+
+    fn part2(trees: &Self::Input<'_>) -> Answer {
+        trees
+            .iter()
+            .map(|(position, &tree_height)| {
+                lines_of_sight(trees, position)
+                    .iter()
+                    .map(|line_of_sight| count_visible_trees(line_of_sight, tree_height))
+                    .product::<u32>()
+            })
+            .max()
+            .expect("grid is not empty")
+            .into()
+    }
+}
+
+/// Generates a map of `size` by `size` tree heights.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| char::from(b'0' + rng.below(10) as u8))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,195 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt::Display,
+};
+
+use crate::{
+    generate::Rng,
+    geometry::{Direction, Point2},
+    grid::{Grid, Position},
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+    visualize::{Frame, Palette, Rgb, Simulation},
+};
+
+#[derive(Clone, Copy)]
+pub struct Movement(Direction, i32);
+
+const PART_1_ROPE_SIZE: usize = 2;
+const PART_2_ROPE_SIZE: usize = 10;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input<'a> = Vec<Movement>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::lines(input, |line| {
+            let direction = line.one_of(&[
+                ('R', Direction::East),
+                ('U', Direction::North),
+                ('L', Direction::West),
+                ('D', Direction::South),
+            ])?;
+            line.tag(" ")?;
+            Ok(Movement(direction, line.number()?))
+        })
+    }
+
+    fn part1(movements: &Self::Input<'_>) -> Answer {
+        run::<PART_1_ROPE_SIZE>(movements).into()
+    }
+
+    fn part2(movements: &Self::Input<'_>) -> Answer {
+        run::<PART_2_ROPE_SIZE>(movements).into()
+    }
+}
+
+struct Rope<const SIZE: usize> {
+    body: [Point2; SIZE],
+    tail_visited_coordinates: HashSet<Point2>,
+}
+
+impl<const SIZE: usize> Rope<SIZE> {
+    fn new() -> Self {
+        let mut tail_visited_coordinates = HashSet::new();
+        tail_visited_coordinates.insert(Point2::ORIGIN);
+        Rope {
+            body: [Point2::ORIGIN; SIZE],
+            tail_visited_coordinates,
+        }
+    }
+
+    fn apply_movement(&mut self, Movement(direction, distance): Movement) {
+        for _ in 0..distance {
+            self.step(direction);
+        }
+    }
+
+    fn step(&mut self, direction: Direction) {
+        self.body[0] += direction.offset();
+        for i in 1..SIZE {
+            let previous_knot = self.body[i - 1];
+            let current_knot = &mut self.body[i];
+            if previous_knot.chebyshev(*current_knot) > 1 {
+                *current_knot += (previous_knot - *current_knot).signum();
+            }
+        }
+        self.tail_visited_coordinates.insert(self.body[SIZE - 1]);
+    }
+
+    /// The knots over the positions the tail visited, as drawn in the puzzle:
+    /// the head is `H`, the other knots are numbered, and `s` is the start.
+    fn frame(&self) -> Frame {
+        let points = || {
+            self.tail_visited_coordinates
+                .iter()
+                .chain(&self.body)
+                .chain([&Point2::ORIGIN])
+        };
+        let top_left = points().fold(Point2::new(i64::MAX, i64::MAX), |corner, point| {
+            Point2::new(corner.x.min(point.x), corner.y.min(point.y))
+        });
+        let bottom_right = points().fold(Point2::new(i64::MIN, i64::MIN), |corner, point| {
+            Point2::new(corner.x.max(point.x), corner.y.max(point.y))
+        });
+        let size = bottom_right - top_left + Point2::new(1, 1);
+        let mut tiles = Grid::new(size.x as usize, size.y as usize, '.');
+        let position = |point: Point2| {
+            Position::try_from(point - top_left).expect("points are within the corners")
+        };
+        for &visited in &self.tail_visited_coordinates {
+            tiles[position(visited)] = '#';
+        }
+        tiles[position(Point2::ORIGIN)] = 's';
+        for (i, &knot) in self.body.iter().enumerate().rev() {
+            tiles[position(knot)] = match i {
+                0 => 'H',
+                _ => char::from_digit(i as u32, 10).expect("ropes have at most 10 knots"),
+            };
+        }
+        Frame {
+            origin: top_left,
+            tiles,
+        }
+    }
+}
+
+/// The rope following its head one step at a time.
+struct Swinging<const SIZE: usize> {
+    rope: Rope<SIZE>,
+    steps: VecDeque<Direction>,
+}
+
+impl<const SIZE: usize> Display for Swinging<SIZE> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.rope.frame().tiles)
+    }
+}
+
+impl<const SIZE: usize> Simulation for Swinging<SIZE> {
+    fn frame(&self) -> Frame {
+        self.rope.frame()
+    }
+
+    fn step(&mut self) -> bool {
+        let Some(direction) = self.steps.pop_front() else {
+            return false;
+        };
+        self.rope.step(direction);
+        true
+    }
+}
+
+/// Moves the rope of 2 knots of part 1, or of 10 knots of part 2.
+pub fn simulate(input: &str, part: u8) -> Result<Box<dyn Simulation>, ParseError> {
+    let steps = Day9::parse(input)?
+        .into_iter()
+        .flat_map(|Movement(direction, distance)| (0..distance).map(move |_| direction))
+        .collect();
+    Ok(match part {
+        1 => Box::new(Swinging::<PART_1_ROPE_SIZE> {
+            rope: Rope::new(),
+            steps,
+        }),
+        _ => Box::new(Swinging::<PART_2_ROPE_SIZE> {
+            rope: Rope::new(),
+            steps,
+        }),
+    })
+}
+
+pub fn palette() -> Palette {
+    let knot = Rgb(240, 160, 64);
+    let mut palette = Palette::new(
+        Rgb(18, 18, 30),
+        &[
+            ('.', Rgb(18, 18, 30)),
+            ('#', Rgb(72, 72, 120)),
+            ('s', Rgb(200, 200, 200)),
+            ('H', Rgb(232, 64, 64)),
+        ],
+    );
+    for i in 1..PART_2_ROPE_SIZE as u32 {
+        palette.set(char::from_digit(i, 10).unwrap(), knot);
+    }
+    palette
+}
+
+fn run<const ROPE_SIZE: usize>(movements: &[Movement]) -> usize {
+    let mut rope = Rope::<ROPE_SIZE>::new();
+
+    for movement in movements {
+        rope.apply_movement(*movement);
+    }
+
+    rope.tail_visited_coordinates.len()
+}
+
+/// Generates `size` motions of the head, each of 1 to 20 steps.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| format!("{} {}", rng.pick(&['R', 'U', 'L', 'D']), rng.range(1..=20)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
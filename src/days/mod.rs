@@ -0,0 +1,235 @@
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+use crate::{
+    generate::Rng,
+    parse::ParseError,
+    solution::{self, Failure, Run, Solution},
+    visualize::{Palette, Simulation},
+};
+
+pub struct Day {
+    pub number: u8,
+    /// Parses an input and solves the given parts of it.
+    pub run: fn(&str, &[u8]) -> Result<Run, Failure>,
+}
+
+impl Day {
+    const fn new<S: Solution>(number: u8) -> Self {
+        Day {
+            number,
+            run: solution::run::<S>,
+        }
+    }
+}
+
+pub const DAYS: [Day; 23] = [
+    Day::new::<day1::Day1>(1),
+    Day::new::<day2::Day2>(2),
+    Day::new::<day3::Day3>(3),
+    Day::new::<day4::Day4>(4),
+    Day::new::<day5::Day5>(5),
+    Day::new::<day6::Day6>(6),
+    Day::new::<day7::Day7>(7),
+    Day::new::<day8::Day8>(8),
+    Day::new::<day9::Day9>(9),
+    Day::new::<day10::Day10>(10),
+    Day::new::<day11::Day11>(11),
+    Day::new::<day12::Day12>(12),
+    Day::new::<day13::Day13>(13),
+    Day::new::<day14::Day14>(14),
+    Day::new::<day15::Day15>(15),
+    Day::new::<day16::Day16>(16),
+    Day::new::<day17::Day17>(17),
+    Day::new::<day18::Day18>(18),
+    Day::new::<day19::Day19>(19),
+    Day::new::<day20::Day20>(20),
+    Day::new::<day21::Day21>(21),
+    Day::new::<day22::Day22>(22),
+    Day::new::<day23::Day23>(23),
+];
+
+/// Builds the simulation of a part from a raw input.
+pub type Simulate = fn(&str, u8) -> Result<Box<dyn Simulation>, ParseError>;
+
+/// A day whose simulation can be recorded as images.
+pub struct Visualization {
+    pub day: u8,
+    pub simulate: Simulate,
+    /// The default colours of the tiles in its frames.
+    pub palette: fn() -> Palette,
+}
+
+pub const VISUALIZATIONS: [Visualization; 4] = [
+    Visualization {
+        day: 9,
+        simulate: day9::simulate,
+        palette: day9::palette,
+    },
+    Visualization {
+        day: 14,
+        simulate: day14::simulate,
+        palette: day14::palette,
+    },
+    Visualization {
+        day: 17,
+        simulate: day17::simulate,
+        palette: day17::palette,
+    },
+    Visualization {
+        day: 23,
+        simulate: day23::simulate,
+        palette: day23::palette,
+    },
+];
+
+/// Generates a random input of the given size, in a unit of the day's own
+/// such as elves, moves or monkeys.
+pub type Generate = fn(&mut Rng, usize) -> String;
+
+/// A day whose inputs can be generated at random.
+pub struct Generator {
+    pub day: u8,
+    pub generate: Generate,
+    /// The size of the real puzzle inputs.
+    pub size: usize,
+}
+
+pub const GENERATORS: [Generator; 23] = [
+    Generator {
+        day: 1,
+        generate: day1::generate,
+        size: 250,
+    },
+    Generator {
+        day: 2,
+        generate: day2::generate,
+        size: 2500,
+    },
+    Generator {
+        day: 3,
+        generate: day3::generate,
+        size: 300,
+    },
+    Generator {
+        day: 4,
+        generate: day4::generate,
+        size: 1000,
+    },
+    Generator {
+        day: 5,
+        generate: day5::generate,
+        size: 500,
+    },
+    Generator {
+        day: 6,
+        generate: day6::generate,
+        size: 4096,
+    },
+    Generator {
+        day: 7,
+        generate: day7::generate,
+        size: 180,
+    },
+    Generator {
+        day: 8,
+        generate: day8::generate,
+        size: 99,
+    },
+    Generator {
+        day: 9,
+        generate: day9::generate,
+        size: 2000,
+    },
+    Generator {
+        day: 10,
+        generate: day10::generate,
+        size: 140,
+    },
+    Generator {
+        day: 11,
+        generate: day11::generate,
+        size: 8,
+    },
+    Generator {
+        day: 12,
+        generate: day12::generate,
+        size: 173,
+    },
+    Generator {
+        day: 13,
+        generate: day13::generate,
+        size: 150,
+    },
+    Generator {
+        day: 14,
+        generate: day14::generate,
+        size: 150,
+    },
+    Generator {
+        day: 15,
+        generate: day15::generate,
+        size: 30,
+    },
+    Generator {
+        day: 16,
+        generate: day16::generate,
+        size: 60,
+    },
+    Generator {
+        day: 17,
+        generate: day17::generate,
+        size: 10091,
+    },
+    Generator {
+        day: 18,
+        generate: day18::generate,
+        size: 2800,
+    },
+    Generator {
+        day: 19,
+        generate: day19::generate,
+        size: 30,
+    },
+    Generator {
+        day: 20,
+        generate: day20::generate,
+        size: 5000,
+    },
+    Generator {
+        day: 21,
+        generate: day21::generate,
+        size: 2000,
+    },
+    Generator {
+        day: 22,
+        generate: day22::generate,
+        size: 50,
+    },
+    Generator {
+        day: 23,
+        generate: day23::generate,
+        size: 70,
+    },
+];
@@ -0,0 +1,143 @@
+use std::path::{Path, PathBuf};
+
+use crate::parse::{Cursor, ParseError};
+
+/// An example input stored together with the answers expected for it.
+///
+/// Fixtures live in `fixtures/day<N>/<name>.txt`. Each expected answer comes
+/// under a `== part <N> ==` header, followed by the input under `== input ==`,
+/// which runs verbatim to the end of the file:
+///
+/// ```text
+/// == part 1 ==
+/// 24
+/// == part 2 ==
+/// 93
+/// == input ==
+/// 498,4 -> 498,6 -> 496,6
+/// 503,4 -> 502,4 -> 502,9 -> 494,9
+/// ```
+///
+/// Answers may span several lines. A part whose solution is not there yet is
+/// marked `== part <N> (ignored) ==`; its test is generated but ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    pub answers: Vec<Expected>,
+    pub input: String,
+}
+
+/// The answer a fixture expects for one part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub part: u8,
+    pub answer: String,
+    pub ignored: bool,
+}
+
+impl Fixture {
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut cursor = Cursor::new(text);
+        let mut answers = Vec::new();
+        loop {
+            cursor.tag("== ")?;
+            if cursor.eat("input ==") {
+                if !cursor.is_at_end() {
+                    cursor.tag("\n")?;
+                }
+                let input = cursor.rest().trim_end_matches(['\n', '\r']);
+                return Ok(Fixture {
+                    answers,
+                    input: input.to_string(),
+                });
+            }
+            answers.push(Self::parse_expected(&mut cursor)?);
+        }
+    }
+
+    fn parse_expected(cursor: &mut Cursor) -> Result<Expected, ParseError> {
+        cursor.tag("part ")?;
+        let start = *cursor;
+        let part = cursor.number()?;
+        if !(1..=2).contains(&part) {
+            return Err(start.error("part 1 or 2"));
+        }
+        let ignored = cursor.eat(" (ignored)");
+        cursor.tag(" ==\n")?;
+        let answer = cursor.rest();
+        while !cursor.is_at_end() && !cursor.rest().starts_with("== ") {
+            cursor.take_line();
+            cursor.eat("\n");
+        }
+        let answer = answer[..answer.len() - cursor.rest().len()].trim_end_matches('\n');
+        Ok(Expected {
+            part,
+            answer: answer.to_string(),
+            ignored,
+        })
+    }
+
+    /// The answer expected for `part`, if the fixture has one.
+    pub fn expected(&self, part: u8) -> Option<&Expected> {
+        self.answers.iter().find(|expected| expected.part == part)
+    }
+}
+
+pub fn path(dir: &Path, day: u8, name: &str) -> PathBuf {
+    crate::input::conventional_path(dir, day, name)
+}
+
+/// Runs `part` of `day` on the fixture `text` and asserts that it gives the
+/// expected answer. Called by the tests generated for every fixture.
+#[cfg(test)]
+fn check(day: u8, name: &str, text: &str, part: u8) {
+    use crate::{answers::Verdict, days::DAYS};
+
+    let fixture = Fixture::parse(text)
+        .unwrap_or_else(|err| panic!("invalid fixture {} of day {}: {}", name, day, err));
+    let expected = fixture.expected(part).expect("fixture has the part");
+    let day = DAYS
+        .iter()
+        .find(|registered| registered.number == day)
+        .unwrap_or_else(|| panic!("day {} is not registered", day));
+    let run = (day.run)(&fixture.input, &[part]).unwrap_or_else(|failure| panic!("{}", failure));
+    match Verdict::new(Some(&expected.answer), &run.parts[0].answer) {
+        Verdict::Pass => {}
+        verdict => panic!("day {} part {} on {}: {}", day.number, part, name, verdict),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixtures_hold_answers_and_input() {
+        let fixture = Fixture::parse(
+            "== part 1 ==\n24\n== part 2 (ignored) ==\n#.\n.#\n== input ==\n 1\n\n2\n",
+        )
+        .unwrap();
+        assert_eq!(
+            fixture.expected(1),
+            Some(&Expected {
+                part: 1,
+                answer: "24".to_string(),
+                ignored: false
+            })
+        );
+        assert_eq!(fixture.expected(2).unwrap().answer, "#.\n.#");
+        assert!(fixture.expected(2).unwrap().ignored);
+        assert_eq!(fixture.input, " 1\n\n2");
+    }
+
+    #[test]
+    fn unknown_sections_are_rejected() {
+        let error = Fixture::parse("== part 3 ==\n1\n== input ==\n1").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 9));
+        assert_eq!(error.expected, "part 1 or 2");
+        assert!(Fixture::parse("1\n2").is_err());
+    }
+
+    mod generated {
+        include!(concat!(env!("OUT_DIR"), "/fixture_tests.rs"));
+    }
+}
@@ -0,0 +1,122 @@
+use std::ops::RangeInclusive;
+
+/// A seeded pseudo-random number generator (SplitMix64), so that a random
+/// input can be generated again from its seed.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, where `n` is positive.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "cannot pick below 0");
+        // Multiplying keeps the bias negligible for the ranges used here.
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    /// A number in `range`, which is not empty.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "cannot pick from an empty range");
+        let span = (end as i128 - start as i128 + 1) as u128;
+        (start as i128 + ((self.next_u64() as u128 * span) >> 64) as i128) as i64
+    }
+
+    /// Whether an event of probability `p` happens.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+
+    /// A word of `length` random lowercase letters.
+    pub fn word(&mut self, length: usize) -> String {
+        (0..length)
+            .map(|_| (b'a' + self.below(26) as u8) as char)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::{DAYS, GENERATORS};
+
+    fn generated(day: u8, seed: u64, size: usize) -> String {
+        let generator = GENERATORS.iter().find(|g| g.day == day).unwrap();
+        (generator.generate)(&mut Rng::new(seed), size)
+    }
+
+    #[test]
+    fn seeds_repeat_their_numbers() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..5).map(|_| rng.range(-3..=3)).collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+        let mut rng = Rng::new(1);
+        assert!((0..1000).all(|_| (-3..=3).contains(&rng.range(-3..=3))));
+        assert_eq!(rng.range(i64::MIN..=i64::MIN), i64::MIN);
+    }
+
+    #[test]
+    fn generated_inputs_parse() {
+        for generator in &GENERATORS {
+            let day = DAYS.iter().find(|day| day.number == generator.day).unwrap();
+            for seed in 0..3 {
+                for size in [1, 2, 10, generator.size] {
+                    let input = generated(day.number, seed, size);
+                    assert_eq!(input, input.trim_end_matches(['\n', '\r']));
+                    if let Err(failure) = (day.run)(&input, &[]) {
+                        panic!(
+                            "day {} seed {} size {}: {}\n{}",
+                            day.number, seed, size, failure, input
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for generator in &GENERATORS {
+            let day = DAYS.iter().find(|day| day.number == generator.day).unwrap();
+            // Small inputs for a few seeds, and one of the size `aoc generate`
+            // produces by default.
+            let runs = (0..3).map(|seed| (seed, 10)).chain([(0, generator.size)]);
+            for (seed, size) in runs {
+                let input = generated(day.number, seed, size);
+                let run = (day.run)(&input, &[1, 2]).unwrap();
+                for part_run in run.parts {
+                    if let Err(failure) = part_run.answer {
+                        panic!(
+                            "day {} part {} seed {} size {}: {}\n{}",
+                            day.number, part_run.part, seed, size, failure, input
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
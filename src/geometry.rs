@@ -0,0 +1,300 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::grid::Position;
+
+/// A point or offset on a plane. As in a [`Grid`](crate::grid::Grid), `y`
+/// grows downwards, so north is `(0, -1)`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan(self, other: Point2) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// The number of king moves between the points.
+    pub fn chebyshev(self, other: Point2) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// The offset of at most one step in each axis towards the same side.
+    pub fn signum(self) -> Point2 {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    /// The 4 points next to this one, clockwise from north.
+    pub fn neighbours4(self) -> [Point2; 4] {
+        Direction::ALL.map(|direction| self + direction.offset())
+    }
+
+    /// The 8 points around this one, clockwise from north.
+    pub fn neighbours8(self) -> [Point2; 8] {
+        Direction8::ALL.map(|direction| self + direction.offset())
+    }
+}
+
+impl From<Position> for Point2 {
+    fn from((x, y): Position) -> Self {
+        Point2::new(x as i64, y as i64)
+    }
+}
+
+/// Fails for points left of or above the origin.
+impl TryFrom<Point2> for Position {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(point: Point2) -> Result<Self, Self::Error> {
+        Ok((usize::try_from(point.x)?, usize::try_from(point.y)?))
+    }
+}
+
+/// A point or offset in space.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3::new(0, 0, 0);
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Point3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(self, other: Point3) -> i64 {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    /// The 6 points sharing a face with this one.
+    pub fn neighbours6(self) -> [Point3; 6] {
+        [
+            Point3::new(-1, 0, 0),
+            Point3::new(1, 0, 0),
+            Point3::new(0, -1, 0),
+            Point3::new(0, 1, 0),
+            Point3::new(0, 0, -1),
+            Point3::new(0, 0, 1),
+        ]
+        .map(|offset| self + offset)
+    }
+}
+
+macro_rules! impl_operators {
+    ($point:ident { $($axis:ident),+ }) => {
+        impl Add for $point {
+            type Output = $point;
+            fn add(self, other: $point) -> $point {
+                $point { $($axis: self.$axis + other.$axis),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+            fn sub(self, other: $point) -> $point {
+                $point { $($axis: self.$axis - other.$axis),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+            fn neg(self) -> $point {
+                $point { $($axis: -self.$axis),+ }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = $point;
+            fn mul(self, factor: i64) -> $point {
+                $point { $($axis: self.$axis * factor),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: $point) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: $point) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_operators!(Point2 { x, y });
+impl_operators!(Point3 { x, y, z });
+
+/// Which way to rotate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Turn {
+    Clockwise,
+    CounterClockwise,
+}
+
+/// One of the 4 cardinal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// The step of length 1 in this direction.
+    pub fn offset(self) -> Point2 {
+        Direction8::from(self).offset()
+    }
+
+    /// The direction after a quarter turn.
+    pub fn turn(self, turn: Turn) -> Direction {
+        let steps = match turn {
+            Turn::Clockwise => 1,
+            Turn::CounterClockwise => 3,
+        };
+        Direction::ALL[(self as usize + steps) % 4]
+    }
+
+    pub fn reverse(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// One of the 4 cardinal or 4 diagonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// The step to the neighbouring point in this direction.
+    pub fn offset(self) -> Point2 {
+        let (x, y) = match self {
+            Direction8::North => (0, -1),
+            Direction8::NorthEast => (1, -1),
+            Direction8::East => (1, 0),
+            Direction8::SouthEast => (1, 1),
+            Direction8::South => (0, 1),
+            Direction8::SouthWest => (-1, 1),
+            Direction8::West => (-1, 0),
+            Direction8::NorthWest => (-1, -1),
+        };
+        Point2::new(x, y)
+    }
+
+    /// The direction after an eighth of a turn.
+    pub fn turn(self, turn: Turn) -> Direction8 {
+        let steps = match turn {
+            Turn::Clockwise => 1,
+            Turn::CounterClockwise => 7,
+        };
+        Direction8::ALL[(self as usize + steps) % 8]
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_support_vector_arithmetic() {
+        let mut point = Point2::new(3, -4);
+        point += Point2::new(1, 1) * 2;
+        assert_eq!(point, Point2::new(5, -2));
+        assert_eq!(-point - Point2::ORIGIN, Point2::new(-5, 2));
+        assert_eq!(point.signum(), Point2::new(1, -1));
+        assert_eq!(
+            Point3::new(1, 2, 3) + Point3::new(1, 1, 1),
+            Point3::new(2, 3, 4)
+        );
+    }
+
+    #[test]
+    fn distances() {
+        let (a, b) = (Point2::new(1, 1), Point2::new(4, -1));
+        assert_eq!(a.manhattan(b), 5);
+        assert_eq!(a.chebyshev(b), 3);
+        let (a, b) = (Point3::new(1, 2, 3), Point3::new(2, 0, 3));
+        assert_eq!((a.manhattan(b), a.chebyshev(b)), (3, 2));
+    }
+
+    #[test]
+    fn turns_go_around_the_compass() {
+        assert_eq!(Direction::West.turn(Turn::Clockwise), Direction::North);
+        assert_eq!(
+            Direction::North.turn(Turn::CounterClockwise),
+            Direction::West
+        );
+        assert_eq!(Direction::East.reverse(), Direction::West);
+        assert_eq!(
+            Direction8::North.turn(Turn::CounterClockwise),
+            Direction8::NorthWest
+        );
+        assert_eq!(Direction::South.offset(), Point2::new(0, 1));
+    }
+
+    #[test]
+    fn neighbours() {
+        assert_eq!(Point2::ORIGIN.neighbours4()[1], Point2::new(1, 0));
+        assert_eq!(Point2::ORIGIN.neighbours8()[7], Point2::new(-1, -1));
+        assert!(Point3::ORIGIN
+            .neighbours6()
+            .iter()
+            .all(|neighbour| neighbour.manhattan(Point3::ORIGIN) == 1));
+        assert_eq!(Position::try_from(Point2::new(2, 3)), Ok((2, 3)));
+        assert!(Position::try_from(Point2::new(-1, 0)).is_err());
+    }
+}
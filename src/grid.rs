@@ -0,0 +1,319 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::parse::{Cursor, ParseError};
+
+/// A position in a grid as `(x, y)`: the column, counted from the left, and
+/// the row, counted from the top.
+pub type Position = (usize, usize);
+
+/// A dense rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parses a character map, one row per line, reading each cell with
+    /// `parse_cell`. Every row must be as wide as the first one.
+    pub fn parse<'a>(
+        cursor: Cursor<'a>,
+        mut parse_cell: impl FnMut(&mut Cursor<'a>) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError>
+    where
+        T: Clone,
+    {
+        Self::parse_rows(cursor, None, &mut parse_cell)
+    }
+
+    /// Parses a character map whose rows may be of different widths, padding
+    /// the short ones on the right with `padding`.
+    pub fn parse_padded<'a>(
+        cursor: Cursor<'a>,
+        padding: T,
+        mut parse_cell: impl FnMut(&mut Cursor<'a>) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError>
+    where
+        T: Clone,
+    {
+        Self::parse_rows(cursor, Some(padding), &mut parse_cell)
+    }
+
+    fn parse_rows<'a>(
+        cursor: Cursor<'a>,
+        padding: Option<T>,
+        parse_cell: &mut impl FnMut(&mut Cursor<'a>) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError>
+    where
+        T: Clone,
+    {
+        let mut rows = Vec::new();
+        let mut width = None;
+        for mut line in cursor.lines() {
+            let mut row = Vec::new();
+            while !line.is_at_end() && (padding.is_some() || width != Some(row.len())) {
+                row.push(parse_cell(&mut line)?);
+            }
+            match width {
+                _ if padding.is_some() => {}
+                None => width = Some(row.len()),
+                Some(width) if row.len() < width => return Err(line.error("a cell")),
+                Some(_) => line.end()?,
+            }
+            rows.push(row);
+        }
+        if rows.iter().all(Vec::is_empty) {
+            return Err(cursor.error("a row of cells"));
+        }
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for mut row in rows {
+            if let Some(padding) = &padding {
+                row.resize(width, padding.clone());
+            }
+            cells.extend(row);
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Adds rows of `fill` after the last one until the grid is `height`
+    /// rows high.
+    pub fn extend_rows(&mut self, height: usize, fill: T)
+    where
+        T: Clone,
+    {
+        if height > self.height {
+            self.cells.resize(height * self.width, fill);
+            self.height = height;
+        }
+    }
+
+    /// Removes the first `count` rows, so that row `count` becomes row 0.
+    pub fn remove_rows(&mut self, count: usize) {
+        assert!(count <= self.height, "cannot remove {} rows", count);
+        self.cells.drain(..count * self.width);
+        self.height -= count;
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.1 * self.width + position.0])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            Some(&mut self.cells[position.1 * self.width + position.0])
+        } else {
+            None
+        }
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The position of the first cell, row by row, for which `predicate` holds.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "column {} out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// The up to 4 positions next to `position` horizontally or vertically
+    /// that lie in the grid, clockwise from the one above.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, &[(0, -1), (1, 0), (0, 1), (-1, 0)])
+    }
+
+    /// The up to 8 positions around `position`, diagonals included, that lie
+    /// in the grid, clockwise from the one above.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(
+            position,
+            &[
+                (0, -1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+                (0, 1),
+                (-1, 1),
+                (-1, 0),
+                (-1, -1),
+            ],
+        )
+    }
+
+    fn neighbours(
+        &self,
+        (x, y): Position,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Position> + '_ {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let neighbour = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+            self.contains(neighbour).then_some(neighbour)
+        })
+    }
+
+    /// Draws the grid with one character per cell and a line break after
+    /// every row.
+    pub fn render(&self, draw: impl Fn(&T) -> char) -> String {
+        let mut rendered = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            rendered.extend(row.iter().map(&draw));
+            rendered.push('\n');
+        }
+        rendered
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("position {:?} out of bounds", position))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("position {:?} out of bounds", position))
+    }
+}
+
+/// Shows every cell followed by a line break after every row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(Cursor::new(input), |cursor| cursor.digit())
+    }
+
+    #[test]
+    fn parsed_grids_are_indexed_by_column_and_row() {
+        let grid = digits("123\n456").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], 3);
+        assert_eq!(grid.get((0, 1)), Some(&4));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+        assert_eq!(grid.find(|&digit| digit > 4), Some((1, 1)));
+        assert_eq!(grid.to_string(), "123\n456\n");
+    }
+
+    #[test]
+    fn rows_are_added_and_removed() {
+        let mut grid = digits("123\n456").unwrap();
+        grid.extend_rows(3, 0);
+        assert_eq!(grid.to_string(), "123\n456\n000\n");
+        grid.extend_rows(1, 9);
+        assert_eq!(grid.height(), 3);
+        grid.remove_rows(2);
+        assert_eq!((grid.width(), grid.height()), (3, 1));
+        assert_eq!(grid.row(0), [0, 0, 0]);
+    }
+
+    #[test]
+    fn rows_must_have_the_same_width() {
+        let error = digits("123\n45").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.expected, "a cell");
+        let error = digits("123\n4567").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.expected.as_str()),
+            (2, 4, "end of line")
+        );
+    }
+
+    #[test]
+    fn padded_rows_are_filled_on_the_right() {
+        let grid = Grid::parse_padded(Cursor::new(" #\n##."), ' ', |cursor| {
+            cursor.satisfy("a tile", |c| " #.".contains(c))
+        })
+        .unwrap();
+        assert_eq!(grid.render(|&c| c), " # \n##.\n");
+    }
+
+    #[test]
+    fn neighbours_stay_in_bounds() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(
+            grid.neighbours8((2, 0)).collect::<Vec<_>>(),
+            [(2, 1), (1, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    }
+}
@@ -0,0 +1,201 @@
+use std::{
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    fixture::{self, Fixture},
+    parse::ParseError,
+};
+
+/// Where a day's puzzle input is read from.
+pub enum Source {
+    /// An explicit input file.
+    File(PathBuf),
+    /// Standard input.
+    Stdin,
+    /// The conventional `<dir>/day<N>/<name>.txt` file.
+    Named { dir: PathBuf, name: String },
+    /// The input of the `<dir>/day<N>/<name>.txt` fixture.
+    Fixture { dir: PathBuf, name: String },
+}
+
+impl Source {
+    /// Builds the source for an `--input` argument, where `-` stands for stdin.
+    pub fn from_arg(arg: PathBuf) -> Self {
+        if arg.as_os_str() == "-" {
+            Source::Stdin
+        } else {
+            Source::File(arg)
+        }
+    }
+
+    fn path(&self, day: u8) -> Option<PathBuf> {
+        match self {
+            Source::File(path) => Some(path.clone()),
+            Source::Stdin => None,
+            Source::Named { dir, name } => Some(conventional_path(dir, day, name)),
+            Source::Fixture { dir, name } => Some(fixture::path(dir, day, name)),
+        }
+    }
+}
+
+/// Shows the input name, the file path or `-` for stdin.
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Stdin => f.write_str("-"),
+            Source::Named { name, .. } | Source::Fixture { name, .. } => f.write_str(name),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing { day: u8, path: PathBuf },
+    Unreadable { path: PathBuf, source: io::Error },
+    Stdin(io::Error),
+    Fixture { path: PathBuf, error: ParseError },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Missing { day, path } => {
+                write!(f, "no input for day {} at {}", day, path.display())
+            }
+            InputError::Unreadable { path, source } => {
+                write!(f, "could not read {}: {}", path.display(), source)
+            }
+            InputError::Stdin(source) => write!(f, "could not read stdin: {}", source),
+            InputError::Fixture { path, error } => {
+                write!(f, "invalid fixture {}: {}", path.display(), error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+pub fn conventional_path(dir: &Path, day: u8, name: &str) -> PathBuf {
+    dir.join(format!("day{}", day))
+        .join(format!("{}.txt", name))
+}
+
+/// Names of the inputs stored for `day` under `dir`, sorted.
+pub fn available(dir: &Path, day: u8) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(dir.join(format!("day{}", day))) else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+        .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+        .collect();
+    names.sort();
+    names
+}
+
+/// Reads the input of `day` from `source`.
+///
+/// Trailing line breaks are dropped, so inputs saved with or without a final
+/// newline parse the same. Only the input part of a fixture is returned.
+pub fn load(day: u8, source: &Source) -> Result<String, InputError> {
+    let input = match source {
+        Source::Fixture { dir, name } => return Ok(load_fixture(dir, day, name)?.input),
+        Source::Stdin => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(InputError::Stdin)?;
+            input
+        }
+        Source::File(_) | Source::Named { .. } => {
+            read(day, source.path(day).expect("files have a path"))?
+        }
+    };
+    Ok(input.trim_end_matches(['\n', '\r']).to_string())
+}
+
+/// Opens the input of `day` from `source` to be read as a stream, without
+/// loading it whole first. Fixtures are small, and are read whole.
+pub fn open(day: u8, source: &Source) -> Result<Box<dyn Read>, InputError> {
+    match source {
+        Source::Fixture { dir, name } => Ok(Box::new(io::Cursor::new(
+            load_fixture(dir, day, name)?.input.into_bytes(),
+        ))),
+        Source::Stdin => Ok(Box::new(io::stdin())),
+        Source::File(_) | Source::Named { .. } => {
+            let path = source.path(day).expect("files have a path");
+            match fs::File::open(&path) {
+                Ok(file) => Ok(Box::new(file)),
+                Err(source) if source.kind() == io::ErrorKind::NotFound => {
+                    Err(InputError::Missing { day, path })
+                }
+                Err(source) => Err(InputError::Unreadable { path, source }),
+            }
+        }
+    }
+}
+
+/// Reads the fixture called `name` of `day` from `dir`.
+pub fn load_fixture(dir: &Path, day: u8, name: &str) -> Result<Fixture, InputError> {
+    let path = fixture::path(dir, day, name);
+    let text = read(day, path.clone())?;
+    Fixture::parse(&text).map_err(|error| InputError::Fixture { path, error })
+}
+
+fn read(day: u8, path: PathBuf) -> Result<String, InputError> {
+    fs::read_to_string(&path).map_err(|source| match source.kind() {
+        io::ErrorKind::NotFound => InputError::Missing { day, path },
+        _ => InputError::Unreadable { path, source },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn named_source_uses_day_directory() {
+        let source = Source::Named {
+            dir: PathBuf::from("inputs"),
+            name: "test".to_string(),
+        };
+        assert_eq!(
+            source.path(14),
+            Some(PathBuf::from("inputs/day14/test.txt"))
+        );
+    }
+
+    #[test]
+    fn missing_file_is_reported() {
+        let source = Source::File(PathBuf::from("does/not/exist.txt"));
+        assert!(matches!(
+            load(3, &source),
+            Err(InputError::Missing { day: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn trailing_newlines_are_dropped() {
+        let path = std::env::temp_dir().join("aoc-input-trailing-newline.txt");
+        fs::write(&path, "1\n2\n\n").unwrap();
+        let input = load(1, &Source::File(path.clone()));
+        fs::remove_file(path).unwrap();
+        assert_eq!(input.unwrap(), "1\n2");
+    }
+
+    #[test]
+    fn fixture_source_reads_the_example_input() {
+        let source = Source::Fixture {
+            dir: PathBuf::from("fixtures"),
+            name: "example".to_string(),
+        };
+        assert_eq!(load(2, &source).unwrap(), "A Y\nB X\nC Z");
+    }
+}
@@ -0,0 +1,20 @@
+pub mod animate;
+pub mod answers;
+pub mod bench;
+pub mod cycle;
+pub mod days;
+pub mod fixture;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod logging;
+pub mod parse;
+pub mod pool;
+pub mod remote;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+pub mod store;
+pub mod submissions;
+pub mod visualize;
@@ -0,0 +1,176 @@
+use std::{fmt::Display, io::Write, str::FromStr};
+
+use log::{LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+/// Which log records to show: a default level, and levels for single
+/// targets such as `day19`, written like `warn,day19=trace,cycle=debug`.
+///
+/// Targets are module paths without the leading `aoc::days::` or `aoc::`, so
+/// every day logs under its own `day<N>` target. A target also covers the
+/// modules nested in it, and a target without a level shows its debug
+/// records.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    default: LevelFilter,
+    targets: Vec<(String, LevelFilter)>,
+}
+
+impl Filter {
+    pub fn new(default: LevelFilter) -> Self {
+        Filter {
+            default,
+            targets: Vec::new(),
+        }
+    }
+
+    /// The filter showing warnings, or debug records with one `-v` and
+    /// everything with more.
+    pub fn from_verbosity(verbosity: u8) -> Self {
+        Filter::new(match verbosity {
+            0 => LevelFilter::Warn,
+            1 => LevelFilter::Debug,
+            _ => LevelFilter::Trace,
+        })
+    }
+
+    /// Adds the targets of `other`, which take precedence over these, and
+    /// shows at least its default level.
+    pub fn extend(&mut self, other: Filter) {
+        self.default = self.default.max(other.default);
+        self.targets.extend(other.targets);
+    }
+
+    /// The most verbose level shown for `target`: that of the most specific
+    /// target covering it, or the default.
+    pub fn level(&self, target: &str) -> LevelFilter {
+        let target = short_target(target);
+        self.targets
+            .iter()
+            .filter(|(name, _)| {
+                target
+                    .strip_prefix(name.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .max_by_key(|(name, _)| name.len())
+            .map_or(self.default, |&(_, level)| level)
+    }
+
+    fn max_level(&self) -> LevelFilter {
+        self.targets
+            .iter()
+            .map(|&(_, level)| level)
+            .fold(self.default, Ord::max)
+    }
+}
+
+impl FromStr for Filter {
+    type Err = FilterError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = Filter::new(LevelFilter::Warn);
+        for directive in s.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            let level = |level: &str| {
+                level
+                    .parse()
+                    .map_err(|_| FilterError(format!("unknown level {:?}", level)))
+            };
+            match directive.split_once('=') {
+                Some((target, level_name)) => filter
+                    .targets
+                    .push((target.to_string(), level(level_name)?)),
+                None => match level(directive) {
+                    Ok(level) => filter.default = level,
+                    Err(_) => filter
+                        .targets
+                        .push((directive.to_string(), LevelFilter::Debug)),
+                },
+            }
+        }
+        Ok(filter)
+    }
+}
+
+/// Why a log filter could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterError(String);
+
+impl Display for FilterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for FilterError {}
+
+/// `target` without the crate and `days` prefixes.
+fn short_target(target: &str) -> &str {
+    target
+        .strip_prefix("aoc::days::")
+        .or_else(|| target.strip_prefix("aoc::"))
+        .unwrap_or(target)
+}
+
+/// Writes the records passing its filter to stderr, so they never mix with
+/// answers on stdout.
+struct Logger {
+    filter: Filter,
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.filter.level(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            let mut err = std::io::stderr().lock();
+            // Nothing better to do when stderr is gone.
+            let _ = writeln!(
+                err,
+                "[{} {}] {}",
+                short_target(record.target()),
+                record.level(),
+                record.args()
+            );
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Installs the logger for the rest of the process.
+pub fn init(filter: Filter) -> Result<(), SetLoggerError> {
+    let max_level = filter.max_level();
+    log::set_boxed_logger(Box::new(Logger { filter }))?;
+    log::set_max_level(max_level);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_most_specific_target_wins() {
+        let filter: Filter = "info,day19=trace,day1,cycle=off".parse().unwrap();
+        assert_eq!(filter.level("aoc::days::day19"), LevelFilter::Trace);
+        assert_eq!(filter.level("aoc::days::day1"), LevelFilter::Debug);
+        assert_eq!(filter.level("aoc::days::day17"), LevelFilter::Info);
+        assert_eq!(filter.level("aoc::cycle"), LevelFilter::Off);
+        assert_eq!(filter.max_level(), LevelFilter::Trace);
+        assert!("day19=loud".parse::<Filter>().is_err());
+    }
+
+    #[test]
+    fn verbosity_raises_the_default_level() {
+        let mut filter = Filter::from_verbosity(0);
+        assert_eq!(filter.level("aoc::days::day19"), LevelFilter::Warn);
+        filter.extend("day19".parse().unwrap());
+        assert_eq!(filter.level("aoc::days::day19"), LevelFilter::Debug);
+        assert_eq!(filter.level("aoc::days::day17"), LevelFilter::Warn);
+        assert_eq!(
+            Filter::from_verbosity(1).level("aoc::days::day17"),
+            LevelFilter::Debug
+        );
+    }
+}
@@ -0,0 +1,1410 @@
+use std::{
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc::{
+    animate::{self, AnimateOptions},
+    answers::{KnownAnswers, Verdict},
+    bench::{self, Baseline, Measurement, Step, Timing},
+    days::{
+        day1,
+        day2::{self, Round, Rules},
+        day3, Day, Visualization, DAYS, GENERATORS, VISUALIZATIONS,
+    },
+    generate::Rng,
+    input::{self, InputError, Source},
+    logging::{self, Filter},
+    pool::{self, Outcome},
+    remote::{self, Client, Config, Fetched, Reply},
+    scaffold,
+    solution::{Answer, Failure, PartRun, Run, Solution},
+    submissions::{self, Submissions},
+    visualize::{self, ExportError, ExportOptions, ImageFormat, Rgb, Simulation},
+};
+use clap::{Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+
+/// Advent of Code 2022 solutions.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Show the debug logs of every day, and with `-vv` their traces too
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+    /// Log levels per target, e.g. `day19=trace` or `info,day17=debug`; a
+    /// target without a level shows its debug logs
+    #[arg(long, global = true, value_name = "FILTER")]
+    log: Option<Filter>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution for one day, or for every day with `--all`
+    Run {
+        /// Day to run
+        #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+        day: Option<u8>,
+        /// Part to run; both parts are run when omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Puzzle input file, or `-` to read it from stdin
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+        /// Name of the input to use from the inputs directory
+        #[arg(long, default_value = "prod", conflicts_with = "input")]
+        name: String,
+        /// Directory holding the `day<N>/<name>.txt` inputs
+        #[arg(long, default_value = "inputs")]
+        inputs_dir: PathBuf,
+        /// Name of the fixture whose example input to use instead
+        #[arg(long, conflicts_with_all = ["input", "name"])]
+        fixture: Option<String>,
+        /// Directory holding the `day<N>/<name>.txt` fixtures
+        #[arg(long, default_value = "fixtures")]
+        fixtures_dir: PathBuf,
+        /// Run every day
+        #[arg(long)]
+        all: bool,
+        /// Number of days to run at once with `--all`; defaults to the
+        /// number of CPUs
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+        jobs: Option<u32>,
+        /// Give up on a day that takes longer than this many seconds
+        #[arg(long)]
+        timeout: Option<f64>,
+        /// How to print the answers
+        #[arg(long, value_enum, default_value = "text")]
+        format: Format,
+    },
+    /// Check the answers for every stored input against the known answers,
+    /// and for every fixture against the answers it expects
+    Verify {
+        /// Only verify this day
+        #[arg(long)]
+        day: Option<u8>,
+        /// Only verify this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Directory holding the `day<N>/<name>.txt` inputs
+        #[arg(long, default_value = "inputs")]
+        inputs_dir: PathBuf,
+        /// Directory holding the `day<N>/<name>.txt` fixtures
+        #[arg(long, default_value = "fixtures")]
+        fixtures_dir: PathBuf,
+        /// File holding the known answers
+        #[arg(long, default_value = "answers.json")]
+        answers: PathBuf,
+        /// Record the answers of solved parts that have no known answer yet
+        #[arg(long)]
+        record: bool,
+    },
+    /// Time the parse step and each part of every day over repeated runs
+    Bench {
+        /// Only benchmark this day
+        #[arg(long)]
+        day: Option<u8>,
+        /// Only benchmark this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Name of the input to use from the inputs directory
+        #[arg(long, default_value = "prod")]
+        name: String,
+        /// Directory holding the `day<N>/<name>.txt` inputs
+        #[arg(long, default_value = "inputs")]
+        inputs_dir: PathBuf,
+        /// Number of timed runs per day
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        /// Compare the timings with the baseline saved in this file
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Save the timings as a baseline to this file
+        #[arg(long)]
+        save: Option<PathBuf>,
+        /// Slowdown of the median, in percent, above which a step counts as regressed
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Record the simulation of a day as a still image of its last frame
+    /// (`.ppm` or `.png`) or as an animated GIF of every frame (`.gif`), or
+    /// play it in the terminal with `--animate`
+    Visualize {
+        /// Day to record: 9, 14, 17 or 23
+        #[arg(long)]
+        day: u8,
+        /// Part whose simulation to record
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Puzzle input file, or `-` to read it from stdin
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Name of the input to use from the inputs directory
+        #[arg(long, default_value = "prod", conflicts_with = "input")]
+        name: String,
+        /// Directory holding the `day<N>/<name>.txt` inputs
+        #[arg(long, default_value = "inputs")]
+        inputs_dir: PathBuf,
+        /// Name of the fixture whose example input to use instead
+        #[arg(long, conflicts_with_all = ["input", "name"])]
+        fixture: Option<String>,
+        /// Directory holding the `day<N>/<name>.txt` fixtures
+        #[arg(long, default_value = "fixtures")]
+        fixtures_dir: PathBuf,
+        /// Image file to write; the format follows its extension
+        #[arg(long, required_unless_present = "animate")]
+        output: Option<PathBuf>,
+        /// Play the simulation in the terminal instead: space pauses, `n`
+        /// steps while paused, `+` and `-` change the speed and `q` quits
+        #[arg(long, conflicts_with = "output")]
+        animate: bool,
+        /// Frames per second when animating
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..=1000))]
+        fps: u32,
+        /// Width and height of a tile in pixels
+        #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..))]
+        scale: u32,
+        /// Record or show a frame every this many steps of the simulation
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+        every: u32,
+        /// Stop the simulation after this many steps
+        #[arg(long)]
+        steps: Option<u32>,
+        /// How long each frame of an animation is shown, in milliseconds
+        #[arg(long, default_value_t = 50)]
+        delay: u64,
+        /// Colour of a tile as `TILE=RRGGBB`, e.g. `#=ff8800`; may be repeated
+        #[arg(long, value_parser = parse_tile_colour)]
+        colour: Vec<(char, Rgb)>,
+    },
+    /// Download the puzzle input of a day into the inputs directory, unless
+    /// it is already there
+    Fetch {
+        /// Day whose input to download
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Name to store the input under
+        #[arg(long, default_value = "prod")]
+        name: String,
+        /// Directory holding the `day<N>/<name>.txt` inputs
+        #[arg(long, default_value = "inputs")]
+        inputs_dir: PathBuf,
+        /// URL of the puzzle server; overrides the one in the config file
+        #[arg(long)]
+        base_url: Option<String>,
+        /// JSON file with the `session` token and `base_url` of the server
+        #[arg(long, default_value = ".aoc.json")]
+        config: PathBuf,
+    },
+    /// Submit the answer to a part of a day, unless earlier submissions show
+    /// it is wrong, and record how the server judged it
+    Submit {
+        /// Day to answer
+        #[arg(long)]
+        day: u8,
+        /// Part to answer
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Answer to submit; computed from the named input when omitted
+        #[arg(long)]
+        answer: Option<String>,
+        /// Name of the input to compute the answer from
+        #[arg(long, default_value = "prod", conflicts_with = "answer")]
+        name: String,
+        /// Directory holding the `day<N>/<name>.txt` inputs
+        #[arg(long, default_value = "inputs")]
+        inputs_dir: PathBuf,
+        /// JSON file recording every submission
+        #[arg(long, default_value = "submissions.json")]
+        submissions: PathBuf,
+        /// URL of the puzzle server; overrides the one in the config file
+        #[arg(long)]
+        base_url: Option<String>,
+        /// JSON file with the `session` token and `base_url` of the server
+        #[arg(long, default_value = ".aoc.json")]
+        config: PathBuf,
+    },
+    /// Start a new day: create its source file with a test module, its
+    /// inputs directory and an example fixture, and register it with the runner
+    New {
+        /// Day to create
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Root of the project to create the day in
+        #[arg(long, default_value = ".")]
+        root: PathBuf,
+    },
+    /// Report on the calories the elves of day 1 carry: both parts, the top
+    /// elves with their ties, and statistics on the totals
+    Calories {
+        /// How many of the elves carrying the most to list, with any ties
+        #[arg(long, default_value_t = 3)]
+        top: usize,
+        /// How many ranges of calories the distribution of the totals has
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        buckets: u64,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = ReportFormat::Text)]
+        format: ReportFormat,
+        /// Read the inventory as a stream in constant space, keeping only the
+        /// top elves: ties, the median and the distribution are left out
+        #[arg(long, conflicts_with = "buckets")]
+        stream: bool,
+        /// Puzzle input file, or `-` to read it from stdin
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Name of the input to use from the inputs directory
+        #[arg(long, default_value = "prod", conflicts_with = "input")]
+        name: String,
+        /// Directory holding the `day<N>/<name>.txt` inputs
+        #[arg(long, default_value = "inputs")]
+        inputs_dir: PathBuf,
+        /// Name of the fixture whose example input to use instead
+        #[arg(long, conflicts_with_all = ["input", "name"])]
+        fixture: Option<String>,
+        /// Directory holding the `day<N>/<name>.txt` fixtures
+        #[arg(long, default_value = "fixtures")]
+        fixtures_dir: PathBuf,
+    },
+    /// Score the strategy guide of day 2 under another rule set, such as
+    /// Rock-Paper-Scissors-Lizard-Spock, or under every reading of its
+    /// second column
+    Shapes {
+        /// JSON file with the shapes, what beats what and the scores; the
+        /// puzzle's rules when omitted
+        #[arg(long)]
+        rules: Option<PathBuf>,
+        /// Part to score; both when omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Score every reading of the second column instead: each assignment
+        /// of shapes to its letters, and the outcomes
+        #[arg(long, conflicts_with = "part")]
+        explore: bool,
+        /// Only list the readings scoring this much
+        #[arg(long, requires = "explore")]
+        target: Option<u32>,
+        /// Puzzle input file, or `-` to read it from stdin
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Name of the input to use from the inputs directory
+        #[arg(long, default_value = "prod", conflicts_with = "input")]
+        name: String,
+        /// Directory holding the `day<N>/<name>.txt` inputs
+        #[arg(long, default_value = "inputs")]
+        inputs_dir: PathBuf,
+        /// Name of the fixture whose example input to use instead
+        #[arg(long, conflicts_with_all = ["input", "name"])]
+        fixture: Option<String>,
+        /// Directory holding the `day<N>/<name>.txt` fixtures
+        #[arg(long, default_value = "fixtures")]
+        fixtures_dir: PathBuf,
+    },
+    /// Sum the priorities of the badges of day 3 for groups of any number of
+    /// elves
+    Badges {
+        /// Number of elves in each group
+        #[arg(long, default_value_t = 3, value_parser = clap::value_parser!(u32).range(1..))]
+        group: u32,
+        /// Puzzle input file, or `-` to read it from stdin
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Name of the input to use from the inputs directory
+        #[arg(long, default_value = "prod", conflicts_with = "input")]
+        name: String,
+        /// Directory holding the `day<N>/<name>.txt` inputs
+        #[arg(long, default_value = "inputs")]
+        inputs_dir: PathBuf,
+        /// Name of the fixture whose example input to use instead
+        #[arg(long, conflicts_with_all = ["input", "name"])]
+        fixture: Option<String>,
+        /// Directory holding the `day<N>/<name>.txt` fixtures
+        #[arg(long, default_value = "fixtures")]
+        fixtures_dir: PathBuf,
+    },
+    /// Generate a random input for a day, of the size of real inputs or any
+    /// other
+    Generate {
+        /// Day whose input to generate
+        #[arg(long)]
+        day: u8,
+        /// Seed of the random numbers; the same seed and size generate the
+        /// same input. A seed is picked and reported when omitted
+        #[arg(long)]
+        seed: Option<u64>,
+        /// Size of the input, counted in the day's own unit such as elves,
+        /// moves or monkeys; defaults to the size of real inputs
+        #[arg(long)]
+        size: Option<usize>,
+        /// File to write the input to instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// List the available days with their inputs and fixtures
+    List {
+        /// Directory holding the `day<N>/<name>.txt` inputs
+        #[arg(long, default_value = "inputs")]
+        inputs_dir: PathBuf,
+        /// Directory holding the `day<N>/<name>.txt` fixtures
+        #[arg(long, default_value = "fixtures")]
+        fixtures_dir: PathBuf,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let mut filter = Filter::from_verbosity(cli.verbose);
+    if let Some(log) = cli.log {
+        filter.extend(log);
+    }
+    logging::init(filter).expect("no logger is installed yet");
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            name,
+            inputs_dir,
+            fixture,
+            fixtures_dir,
+            all: _,
+            jobs,
+            timeout,
+            format,
+        } => {
+            let Some(days) = select_days(day) else {
+                return ExitCode::FAILURE;
+            };
+            let timeout = match timeout.map(Duration::try_from_secs_f64).transpose() {
+                Ok(timeout) => timeout,
+                Err(err) => {
+                    eprintln!("invalid timeout: {}", err);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let jobs = jobs.map_or_else(
+                || thread::available_parallelism().map_or(1, usize::from),
+                |jobs| jobs as usize,
+            );
+            let options = RunOptions {
+                parts: select_parts(part),
+                explicit_part: part.is_some(),
+                jobs,
+                timeout,
+                format,
+            };
+            let source = select_source(input, fixture, name, inputs_dir, fixtures_dir);
+            if run_days(&days, &source, &options) {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Command::Verify {
+            day,
+            part,
+            inputs_dir,
+            fixtures_dir,
+            answers,
+            record,
+        } => {
+            let Some(days) = select_days(day) else {
+                return ExitCode::FAILURE;
+            };
+            let dirs = Dirs {
+                inputs: &inputs_dir,
+                fixtures: &fixtures_dir,
+            };
+            verify(&days, &select_parts(part), &dirs, &answers, record)
+        }
+        Command::Bench {
+            day,
+            part,
+            name,
+            inputs_dir,
+            runs,
+            baseline,
+            save,
+            threshold,
+        } => {
+            let Some(days) = select_days(day) else {
+                return ExitCode::FAILURE;
+            };
+            let baseline = match baseline.as_deref().map(Baseline::load).transpose() {
+                Ok(baseline) => baseline,
+                Err(err) => {
+                    eprintln!("{}", err);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let options = BenchOptions {
+                parts: select_parts(part),
+                runs: runs as usize,
+                baseline,
+                threshold,
+            };
+            benchmark(&days, &inputs_dir, &name, &options, save.as_deref())
+        }
+        Command::Visualize {
+            day,
+            part,
+            input,
+            name,
+            inputs_dir,
+            fixture,
+            fixtures_dir,
+            output,
+            animate,
+            fps,
+            scale,
+            every,
+            steps,
+            delay,
+            colour,
+        } => {
+            let Some(visualization) = VISUALIZATIONS.iter().find(|v| v.day == day) else {
+                eprintln!("Day {} has no simulation to visualize", day);
+                return ExitCode::FAILURE;
+            };
+            let source = select_source(input, fixture, name, inputs_dir, fixtures_dir);
+            let Some(mut simulation) = load_simulation(visualization, &source, part) else {
+                return ExitCode::FAILURE;
+            };
+            let (every, steps) = (every as usize, steps.map(|steps| steps as usize));
+            let Some(output) = output.filter(|_| !animate) else {
+                let options = AnimateOptions {
+                    frames_per_second: fps,
+                    every,
+                    limit: steps,
+                };
+                return match animate::animate(simulation.as_mut(), &options) {
+                    Ok(()) => ExitCode::SUCCESS,
+                    Err(err) => {
+                        eprintln!("could not animate in the terminal: {}", err);
+                        ExitCode::FAILURE
+                    }
+                };
+            };
+            let mut palette = (visualization.palette)();
+            for (tile, colour) in colour {
+                palette.set(tile, colour);
+            }
+            let options = ExportOptions {
+                palette,
+                scale: scale as usize,
+                delay: Duration::from_millis(delay),
+            };
+            export(simulation.as_mut(), every, steps, &options, &output)
+        }
+        Command::Fetch {
+            day,
+            name,
+            inputs_dir,
+            base_url,
+            config,
+        } => {
+            let Some(client) = connect(&config, base_url) else {
+                return ExitCode::FAILURE;
+            };
+            match remote::fetch_input(&client, &inputs_dir, day, &name) {
+                Ok(Fetched::Cached(path)) => {
+                    println!("Day {}: {} is already downloaded", day, path.display());
+                    ExitCode::SUCCESS
+                }
+                Ok(Fetched::Downloaded(path)) => {
+                    println!("Day {}: downloaded {}", day, path.display());
+                    ExitCode::SUCCESS
+                }
+                Err(err) => {
+                    eprintln!("Day {}: {}", day, err);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Submit {
+            day,
+            part,
+            answer,
+            name,
+            inputs_dir,
+            submissions,
+            base_url,
+            config,
+        } => {
+            let Some(days) = select_days(Some(day)) else {
+                return ExitCode::FAILURE;
+            };
+            let answer = match answer {
+                Some(answer) => answer,
+                None => {
+                    let source = Source::Named {
+                        dir: inputs_dir,
+                        name,
+                    };
+                    match compute_answer(days[0], part, &source) {
+                        Ok(answer) => answer,
+                        Err(err) => {
+                            eprintln!("Day {} part {}: {}", day, part, err);
+                            return ExitCode::FAILURE;
+                        }
+                    }
+                }
+            };
+            let Some(client) = connect(&config, base_url) else {
+                return ExitCode::FAILURE;
+            };
+            submit(&client, &submissions, day, part, &answer)
+        }
+        Command::New { day, root } => match scaffold::create(&root, day) {
+            Ok(paths) => {
+                for path in paths {
+                    println!("Day {}: created {}", day, path.display());
+                }
+                println!(
+                    "Day {}: registered in {}",
+                    day,
+                    root.join("src/days/mod.rs").display()
+                );
+                println!(
+                    "Day {}: fill in the example answers and input, then `aoc fetch --day {}`",
+                    day, day
+                );
+                ExitCode::SUCCESS
+            }
+            Err(err) => {
+                eprintln!("Day {}: {}", day, err);
+                ExitCode::FAILURE
+            }
+        },
+        Command::Calories {
+            top,
+            buckets,
+            format,
+            stream,
+            input,
+            name,
+            inputs_dir,
+            fixture,
+            fixtures_dir,
+        } => {
+            let source = select_source(input, fixture, name, inputs_dir, fixtures_dir);
+            let statistics = if stream {
+                input::open(1, &source)
+                    .map_err(|err| err.to_string())
+                    .and_then(|reader| day1::stream(reader, top).map_err(|err| err.to_string()))
+                    .map(|tally| day1::report_tally(&tally, top))
+            } else {
+                input::load(1, &source)
+                    .map_err(|err| err.to_string())
+                    .and_then(|input| {
+                        day1::Day1::parse(&input).map_err(|err| format!("invalid input: {}", err))
+                    })
+                    .map(|elves| day1::report(&elves, top, buckets as usize))
+            };
+            let statistics = match statistics {
+                Ok(statistics) => statistics,
+                Err(err) => {
+                    eprintln!("Day 1: {}", err);
+                    return ExitCode::FAILURE;
+                }
+            };
+            match format {
+                ReportFormat::Text => print!("{}", day1::report_text(&statistics)),
+                ReportFormat::Csv => print!("{}", day1::report_csv(&statistics)),
+            }
+            ExitCode::SUCCESS
+        }
+        Command::Shapes {
+            rules,
+            part,
+            explore,
+            target,
+            input,
+            name,
+            inputs_dir,
+            fixture,
+            fixtures_dir,
+        } => {
+            let rules = match rules.map_or_else(|| Ok(Rules::classic()), |path| Rules::load(&path))
+            {
+                Ok(rules) => rules,
+                Err(err) => {
+                    eprintln!("Day 2: {}", err);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let source = select_source(input, fixture, name, inputs_dir, fixtures_dir);
+            let rounds = match input::load(2, &source) {
+                Ok(input) => rules
+                    .parse_guide(&input)
+                    .map_err(|err| format!("invalid input: {}", err)),
+                Err(err) => Err(err.to_string()),
+            };
+            let rounds = match rounds {
+                Ok(rounds) => rounds,
+                Err(err) => {
+                    eprintln!("Day 2: {}", err);
+                    return ExitCode::FAILURE;
+                }
+            };
+            if explore {
+                return explore_shapes(&rules, &rounds, target);
+            }
+            let mut status = ExitCode::SUCCESS;
+            for part in select_parts(part) {
+                let score = match part {
+                    1 => rules.score_shapes(&rounds),
+                    _ => rules.score_outcomes(&rounds),
+                };
+                match score {
+                    Ok(score) => println!("Day 2 part {}: {}", part, score),
+                    Err(err) => {
+                        eprintln!("Day 2 part {}: {}", part, err);
+                        status = ExitCode::FAILURE;
+                    }
+                }
+            }
+            status
+        }
+        Command::Badges {
+            group,
+            input,
+            name,
+            inputs_dir,
+            fixture,
+            fixtures_dir,
+        } => {
+            let source = select_source(input, fixture, name, inputs_dir, fixtures_dir);
+            let sum = match input::load(3, &source) {
+                Ok(input) => day3::parse_rucksacks(&input)
+                    .map_err(|err| format!("invalid input: {}", err))
+                    .and_then(|rucksacks| {
+                        day3::badge_priorities(&rucksacks, group as usize)
+                            .map_err(|err| err.to_string())
+                    }),
+                Err(err) => Err(err.to_string()),
+            };
+            match sum {
+                Ok(sum) => {
+                    println!("Day 3 badges in groups of {}: {}", group, sum);
+                    ExitCode::SUCCESS
+                }
+                Err(err) => {
+                    eprintln!("Day 3: {}", err);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Generate {
+            day,
+            seed,
+            size,
+            output,
+        } => {
+            let Some(generator) = GENERATORS.iter().find(|g| g.day == day) else {
+                eprintln!("Day {} has no input generator", day);
+                return ExitCode::FAILURE;
+            };
+            let seed = seed.unwrap_or_else(|| {
+                let seed = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |elapsed| elapsed.as_nanos() as u64);
+                eprintln!("Day {}: seed {}", day, seed);
+                seed
+            });
+            let input = (generator.generate)(&mut Rng::new(seed), size.unwrap_or(generator.size));
+            let Some(output) = output else {
+                println!("{}", input);
+                return ExitCode::SUCCESS;
+            };
+            if let Some(dir) = output.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+                if let Err(err) = fs::create_dir_all(dir) {
+                    eprintln!("Day {}: {}: {}", day, dir.display(), err);
+                    return ExitCode::FAILURE;
+                }
+            }
+            match fs::write(&output, input + "\n") {
+                Ok(()) => {
+                    println!("Day {}: wrote {}", day, output.display());
+                    ExitCode::SUCCESS
+                }
+                Err(err) => {
+                    eprintln!("Day {}: {}: {}", day, output.display(), err);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::List {
+            inputs_dir,
+            fixtures_dir,
+        } => {
+            for day in DAYS.iter() {
+                let names = input::available(&inputs_dir, day.number);
+                let inputs = if names.is_empty() {
+                    "no inputs".to_string()
+                } else {
+                    names.join(", ")
+                };
+                let fixtures = input::available(&fixtures_dir, day.number);
+                if fixtures.is_empty() {
+                    println!("Day {}: {}", day.number, inputs);
+                } else {
+                    println!(
+                        "Day {}: {}; fixtures: {}",
+                        day.number,
+                        inputs,
+                        fixtures.join(", ")
+                    );
+                }
+            }
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+/// A client for the puzzle server, configured from `config_path` with
+/// `base_url` taking precedence over the configured URL.
+fn connect(config_path: &Path, base_url: Option<String>) -> Option<Client> {
+    let config = match Config::load(config_path) {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("{}", err);
+            return None;
+        }
+    };
+    let base_url = base_url
+        .or_else(|| config.base_url.clone())
+        .unwrap_or_else(|| remote::DEFAULT_BASE_URL.to_string());
+    Some(Client::new(&base_url, config.session()))
+}
+
+/// The answer of `part` of `day` on the input from `source`, as it would be
+/// submitted.
+fn compute_answer(day: &Day, part: u8, source: &Source) -> Result<String, String> {
+    let input = input::load(day.number, source).map_err(|err| err.to_string())?;
+    let run = (day.run)(&input, &[part]).map_err(|failure| failure.to_string())?;
+    match run.parts.into_iter().next().map(|part_run| part_run.answer) {
+        Some(Ok(Answer::Unsolved)) | None => Err("unsolved".to_string()),
+        Some(Ok(answer)) => Ok(answer.to_string().trim_end().to_string()),
+        Some(Err(failure)) => Err(failure.to_string()),
+    }
+}
+
+/// Submits `answer` unless the submissions recorded in `submissions_path`
+/// rule it out, then records the reply there.
+fn submit(client: &Client, submissions_path: &Path, day: u8, part: u8, answer: &str) -> ExitCode {
+    let mut submissions = match Submissions::load(submissions_path) {
+        Ok(submissions) => submissions,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let result = submissions::submit(client, &mut submissions, day, part, answer);
+    if let Err(err) = submissions.save(submissions_path) {
+        eprintln!("{}", err);
+        return ExitCode::FAILURE;
+    }
+    match result {
+        Ok(reply) => {
+            println!("Day {} part {}: {} is {}", day, part, answer, reply);
+            if reply == Reply::Correct {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Err(err) => {
+            eprintln!("Day {} part {}: {} {}", day, part, answer, err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Where to read the input from: an explicit file, a fixture, or a named
+/// input from the inputs directory.
+fn select_source(
+    input: Option<PathBuf>,
+    fixture: Option<String>,
+    name: String,
+    inputs_dir: PathBuf,
+    fixtures_dir: PathBuf,
+) -> Source {
+    match (input, fixture) {
+        (Some(path), _) => Source::from_arg(path),
+        (None, Some(name)) => Source::Fixture {
+            dir: fixtures_dir,
+            name,
+        },
+        (None, None) => Source::Named {
+            dir: inputs_dir,
+            name,
+        },
+    }
+}
+
+/// Prints the score of the guide under every reading of its second column,
+/// or only the readings scoring `target`.
+fn explore_shapes(rules: &Rules, rounds: &[Round], target: Option<u32>) -> ExitCode {
+    let mut found = false;
+    for (interpretation, score) in day2::explore(rules, rounds) {
+        let description = rules.describe(&interpretation);
+        match score {
+            Ok(score) if target.is_none_or(|target| target == score) => {
+                println!("{}: {}", description, score);
+                found = true;
+            }
+            Ok(_) => {}
+            Err(err) if target.is_none() => eprintln!("{}: {}", description, err),
+            Err(_) => {}
+        }
+    }
+    match target {
+        Some(target) if !found => {
+            eprintln!("Day 2: no reading of the guide scores {}", target);
+            ExitCode::FAILURE
+        }
+        _ => ExitCode::SUCCESS,
+    }
+}
+
+fn select_parts(part: Option<u8>) -> Vec<u8> {
+    match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    }
+}
+
+/// The requested day, or every day when none is given.
+fn select_days(day: Option<u8>) -> Option<Vec<&'static Day>> {
+    match day {
+        Some(number) => match DAYS.iter().find(|day| day.number == number) {
+            Some(day) => Some(vec![day]),
+            None => {
+                eprintln!("Day {} is not solved", number);
+                None
+            }
+        },
+        None => Some(DAYS.iter().collect()),
+    }
+}
+
+struct Dirs<'a> {
+    inputs: &'a Path,
+    fixtures: &'a Path,
+}
+
+/// Runs `parts` of `days` on every stored input and compares the answers with
+/// the known answers in `answers_path`, optionally recording missing ones.
+/// Every fixture is checked against the answers it expects as well.
+fn verify(days: &[&Day], parts: &[u8], dirs: &Dirs, answers_path: &Path, record: bool) -> ExitCode {
+    let mut answers = match KnownAnswers::load(answers_path) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let (mut passed, mut failed, mut missing, mut recorded) = (0, 0, 0, 0);
+    for day in days {
+        let names = input::available(dirs.inputs, day.number);
+        for known in answers.iter().filter(|known| {
+            known.day == day.number && parts.contains(&known.part) && !names.contains(&known.input)
+        }) {
+            println!(
+                "Day {} part {} ({}): no input",
+                known.day, known.part, known.input
+            );
+            missing += 1;
+        }
+        for name in names {
+            let source = Source::Named {
+                dir: dirs.inputs.to_path_buf(),
+                name: name.clone(),
+            };
+            let run = input::load(day.number, &source)
+                .map_err(|err| err.to_string())
+                .and_then(|input| (day.run)(&input, parts).map_err(|failure| failure.to_string()));
+            let run = match run {
+                Ok(run) => run,
+                Err(err) => {
+                    println!("Day {} ({}): FAIL ({})", day.number, name, err);
+                    failed += 1;
+                    continue;
+                }
+            };
+            for part_run in run.parts {
+                let verdict = Verdict::new(
+                    answers.get(day.number, part_run.part, &name),
+                    &part_run.answer,
+                );
+                println!(
+                    "Day {} part {} ({}): {}",
+                    day.number, part_run.part, name, verdict
+                );
+                match verdict {
+                    Verdict::Pass => passed += 1,
+                    Verdict::Missing { .. } => {
+                        missing += 1;
+                        if let (true, Ok(answer)) = (record, &part_run.answer) {
+                            answers.record(day.number, part_run.part, &name, answer);
+                            recorded += 1;
+                        }
+                    }
+                    Verdict::Unsolved => {}
+                    Verdict::Fail { .. } | Verdict::Error(_) => failed += 1,
+                }
+            }
+        }
+        let (fixtures_passed, fixtures_failed) = verify_fixtures(day, parts, dirs.fixtures);
+        passed += fixtures_passed;
+        failed += fixtures_failed;
+    }
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    if recorded > 0 {
+        if let Err(err) = answers.save(answers_path) {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+        println!(
+            "Recorded {} answers in {}",
+            recorded,
+            answers_path.display()
+        );
+    }
+    if failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Runs `parts` of `day` on each of its fixtures that expects an answer for
+/// them, returning how many passed and failed. Ignored parts are not run.
+fn verify_fixtures(day: &Day, parts: &[u8], fixtures_dir: &Path) -> (usize, usize) {
+    let (mut passed, mut failed) = (0, 0);
+    for name in input::available(fixtures_dir, day.number) {
+        let fixture = match input::load_fixture(fixtures_dir, day.number, &name) {
+            Ok(fixture) => fixture,
+            Err(err) => {
+                println!("Day {} (fixture {}): FAIL ({})", day.number, name, err);
+                failed += 1;
+                continue;
+            }
+        };
+        let mut checked = Vec::new();
+        for &part in parts {
+            match fixture.expected(part) {
+                Some(expected) if expected.ignored => {
+                    println!(
+                        "Day {} part {} (fixture {}): ignored",
+                        day.number, part, name
+                    );
+                }
+                Some(_) => checked.push(part),
+                None => {}
+            }
+        }
+        if checked.is_empty() {
+            continue;
+        }
+        let run = match (day.run)(&fixture.input, &checked) {
+            Ok(run) => run,
+            Err(failure) => {
+                println!("Day {} (fixture {}): FAIL ({})", day.number, name, failure);
+                failed += checked.len();
+                continue;
+            }
+        };
+        for part_run in run.parts {
+            let expected = fixture
+                .expected(part_run.part)
+                .map(|expected| expected.answer.as_str());
+            let verdict = Verdict::new(expected, &part_run.answer);
+            println!(
+                "Day {} part {} (fixture {}): {}",
+                day.number, part_run.part, name, verdict
+            );
+            if verdict.is_failure() {
+                failed += 1;
+            } else {
+                passed += 1;
+            }
+        }
+    }
+    (passed, failed)
+}
+
+struct BenchOptions {
+    parts: Vec<u8>,
+    runs: usize,
+    baseline: Option<Baseline>,
+    threshold: f64,
+}
+
+/// Benchmarks `days` on their input called `name`, printing a table of median
+/// timings and, when a baseline is given, how they changed.
+fn benchmark(
+    days: &[&Day],
+    inputs_dir: &Path,
+    name: &str,
+    options: &BenchOptions,
+    save: Option<&Path>,
+) -> ExitCode {
+    let source = Source::Named {
+        dir: inputs_dir.to_path_buf(),
+        name: name.to_string(),
+    };
+    let mut succeeded = true;
+    let mut timings = Vec::new();
+    let mut regressions = Vec::new();
+    println!(
+        "{:>6}  {:>18}  {:>18}  {:>18}",
+        "Day", "Parse", "Part 1", "Part 2"
+    );
+    for day in days {
+        let input = match input::load(day.number, &source) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("Day {}: {}", day.number, err);
+                succeeded = false;
+                continue;
+            }
+        };
+        let Measurement {
+            timings: day_timings,
+            failures,
+        } = bench::measure(day, name, &input, &options.parts, options.runs);
+        let cells: Vec<String> = [Step::Parse, Step::Part1, Step::Part2]
+            .into_iter()
+            .map(|step| {
+                if failures.iter().any(|(failed, _)| *failed == step) {
+                    return "failed".to_string();
+                }
+                let Some(timing) = day_timings.iter().find(|timing| timing.step == step) else {
+                    return "-".to_string();
+                };
+                let median = bench::format_duration(timing.median());
+                let change = options
+                    .baseline
+                    .as_ref()
+                    .and_then(|baseline| baseline.compare(timing, options.threshold));
+                match change {
+                    Some(change) if change.regressed => {
+                        regressions.push((timing.clone(), change));
+                        format!("{} ({})!", median, change)
+                    }
+                    Some(change) => format!("{} ({})", median, change),
+                    None => median,
+                }
+            })
+            .collect();
+        println!(
+            "{:>6}  {:>18}  {:>18}  {:>18}",
+            day.number, cells[0], cells[1], cells[2]
+        );
+        for (step, failure) in &failures {
+            println!("Day {} {}: {}", day.number, step, failure);
+            succeeded = false;
+        }
+        timings.extend(day_timings);
+    }
+    for (
+        Timing {
+            day, input, step, ..
+        },
+        change,
+    ) in &regressions
+    {
+        println!(
+            "Day {} {} ({}) regressed by {} against {}",
+            day,
+            step,
+            input,
+            change,
+            bench::format_duration(change.baseline)
+        );
+    }
+    if let Some(path) = save {
+        if let Err(err) = Baseline::new(timings).save(path) {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+        println!("Saved baseline to {}", path.display());
+    }
+    if succeeded && regressions.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// The simulation of `part` of a visualized day on the input from `source`,
+/// or `None` after reporting why there is none.
+fn load_simulation(
+    visualization: &Visualization,
+    source: &Source,
+    part: u8,
+) -> Option<Box<dyn Simulation>> {
+    let day = visualization.day;
+    let input = match input::load(day, source) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Day {}: {}", day, err);
+            return None;
+        }
+    };
+    match (visualization.simulate)(&input, part) {
+        Ok(simulation) => Some(simulation),
+        Err(err) => {
+            eprintln!("Day {}: invalid input: {}", day, err);
+            None
+        }
+    }
+}
+
+/// Records `simulation`, a frame every `every` steps for at most `steps`
+/// steps, and exports the frames to `output`.
+fn export(
+    simulation: &mut dyn Simulation,
+    every: usize,
+    steps: Option<usize>,
+    options: &ExportOptions,
+    output: &Path,
+) -> ExitCode {
+    let Some(format) = ImageFormat::from_path(output) else {
+        eprintln!("{}", ExportError::UnknownFormat(output.to_path_buf()));
+        return ExitCode::FAILURE;
+    };
+    // A still only shows the last frame, which is always recorded.
+    let every = match format {
+        ImageFormat::Gif => every,
+        ImageFormat::Ppm | ImageFormat::Png => usize::MAX,
+    };
+    let frames = visualize::record(simulation, every, steps);
+    if let Err(err) = visualize::export(output, &frames, options) {
+        eprintln!("{}", err);
+        return ExitCode::FAILURE;
+    }
+    match format {
+        ImageFormat::Gif => println!("Wrote {} frames to {}", frames.len(), output.display()),
+        ImageFormat::Ppm | ImageFormat::Png => println!("Wrote {}", output.display()),
+    }
+    ExitCode::SUCCESS
+}
+
+/// Parses a `TILE=RRGGBB` colour argument.
+fn parse_tile_colour(arg: &str) -> Result<(char, Rgb), String> {
+    let mut chars = arg.chars();
+    match (chars.next(), chars.next()) {
+        (Some(tile), Some('=')) => Ok((tile, chars.as_str().parse()?)),
+        _ => Err(format!("expected TILE=RRGGBB, found {:?}", arg)),
+    }
+}
+
+struct RunOptions {
+    parts: Vec<u8>,
+    /// Whether the parts were explicitly requested.
+    explicit_part: bool,
+    jobs: usize,
+    timeout: Option<Duration>,
+    format: Format,
+}
+
+/// What running a day on its input gave.
+type DayResult = Result<Result<Run, Failure>, InputError>;
+
+/// Runs the requested parts of `days` on up to `options.jobs` days at once,
+/// printing the answers of each day in order. Returns whether every day
+/// succeeded.
+fn run_days(days: &[&'static Day], source: &Source, options: &RunOptions) -> bool {
+    let inputs = days
+        .iter()
+        .map(|&day| (day, input::load(day.number, source)))
+        .collect();
+    let parts = options.parts.clone();
+    let task = move |(day, input): (&'static Day, Result<String, InputError>)| -> DayResult {
+        input.map(|input| (day.run)(&input, &parts))
+    };
+    let mut succeeded = true;
+    pool::run(
+        inputs,
+        options.jobs,
+        options.timeout,
+        task,
+        |index, outcome| {
+            succeeded &= report_day(days[index], source, outcome, options);
+        },
+    );
+    succeeded
+}
+
+/// Prints the answer to every part run on `day`, or why there are none.
+///
+/// An unsolved part only counts as a failure when it was explicitly requested.
+fn report_day(
+    day: &Day,
+    source: &Source,
+    outcome: Outcome<DayResult>,
+    options: &RunOptions,
+) -> bool {
+    let (format, parts) = (options.format, &options.parts);
+    let run = match outcome {
+        Outcome::Done(Ok(Ok(run))) => run,
+        Outcome::Done(Err(err)) => {
+            match format {
+                Format::Text => eprintln!("Day {}: {}", day.number, err),
+                Format::Json => print_failed_records(day, parts, source, Status::Failed, &err),
+            }
+            return false;
+        }
+        Outcome::Done(Ok(Err(failure))) => {
+            match format {
+                Format::Text => println!("Day {}: {}", day.number, failure),
+                Format::Json => print_failed_records(day, parts, source, Status::Failed, &failure),
+            }
+            return false;
+        }
+        Outcome::TimedOut => {
+            let timeout = options.timeout.expect("only days with a timeout time out");
+            let error = format!("timed out after {}", bench::format_duration(timeout));
+            match format {
+                Format::Text => println!("Day {}: {}", day.number, error),
+                Format::Json => print_failed_records(day, parts, source, Status::TimedOut, &error),
+            }
+            return false;
+        }
+        Outcome::Panicked => {
+            match format {
+                Format::Text => println!("Day {}: panicked", day.number),
+                Format::Json => {
+                    print_failed_records(day, parts, source, Status::Failed, &"panicked")
+                }
+            }
+            return false;
+        }
+    };
+    let mut succeeded = true;
+    for part_run in run.parts {
+        match part_run.answer {
+            Ok(Answer::Unsolved) => succeeded &= !options.explicit_part,
+            Ok(_) => {}
+            Err(_) => succeeded = false,
+        }
+        match format {
+            Format::Text => print_part(day, &part_run),
+            Format::Json => print_record(&Record::new(day, source, &part_run)),
+        }
+    }
+    succeeded
+}
+
+fn print_part(day: &Day, part_run: &PartRun) {
+    let label = format!("Day {} part {}", day.number, part_run.part);
+    match &part_run.answer {
+        Ok(Answer::Unsolved) => println!("{}: unsolved", label),
+        Ok(answer) => {
+            let answer = answer.to_string();
+            if answer.contains('\n') {
+                println!("{}:\n{}", label, answer.trim_end());
+            } else {
+                println!("{}: {}", label, answer);
+            }
+        }
+        Err(failure) => println!("{}: {}", label, failure),
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ReportFormat {
+    /// Aligned columns
+    Text,
+    /// A header and one row per statistic
+    Csv,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// One human-readable line per part
+    Text,
+    /// One JSON record per line for each day, part and input
+    Json,
+}
+
+#[derive(Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Status {
+    Solved,
+    Unsolved,
+    Failed,
+    TimedOut,
+}
+
+/// The outcome of one part of a day on one input, as printed by `--format json`.
+#[derive(Serialize)]
+struct Record {
+    day: u8,
+    part: u8,
+    input: String,
+    status: Status,
+    answer: Option<String>,
+    elapsed_nanos: Option<u64>,
+    error: Option<String>,
+}
+
+impl Record {
+    fn new(day: &Day, source: &Source, part_run: &PartRun) -> Self {
+        let (status, answer, error) = match &part_run.answer {
+            Ok(Answer::Unsolved) => (Status::Unsolved, None, None),
+            Ok(answer) => (Status::Solved, Some(answer.to_string()), None),
+            Err(failure) => (Status::Failed, None, Some(failure.to_string())),
+        };
+        Record {
+            day: day.number,
+            part: part_run.part,
+            input: source.to_string(),
+            status,
+            answer,
+            elapsed_nanos: Some(u64::try_from(part_run.elapsed.as_nanos()).unwrap_or(u64::MAX)),
+            error,
+        }
+    }
+
+    fn failed(day: &Day, part: u8, source: &Source, status: Status, error: &dyn Display) -> Self {
+        Record {
+            day: day.number,
+            part,
+            input: source.to_string(),
+            status,
+            answer: None,
+            elapsed_nanos: None,
+            error: Some(error.to_string()),
+        }
+    }
+}
+
+fn print_record(record: &Record) {
+    println!(
+        "{}",
+        serde_json::to_string(record).expect("records serialize to JSON")
+    );
+}
+
+/// Prints a failed record for each of `parts` when the whole day failed.
+fn print_failed_records(
+    day: &Day,
+    parts: &[u8],
+    source: &Source,
+    status: Status,
+    error: &dyn Display,
+) {
+    for &part in parts {
+        print_record(&Record::failed(day, part, source, status, error));
+    }
+}
@@ -0,0 +1,297 @@
+use std::{fmt::Display, str::FromStr};
+
+/// Why an input could not be parsed, and where.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line of the offending token.
+    pub line: usize,
+    /// 1-based column of the offending token, counted in characters.
+    pub column: usize,
+    /// What the parser was looking for.
+    pub expected: String,
+    /// What it found instead.
+    pub found: String,
+}
+
+impl ParseError {
+    /// Builds the error for `rest`, a slice of `input`, not starting with `expected`.
+    pub fn at(input: &str, rest: &str, expected: impl Into<String>) -> Self {
+        let offset = rest.as_ptr() as usize - input.as_ptr() as usize;
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.into(),
+            found: describe(&input[offset..]),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Describes the token at the start of `rest` for an error message.
+fn describe(rest: &str) -> String {
+    let token = match rest.chars().next() {
+        None => return "end of input".to_string(),
+        Some('\n' | '\r') => return "end of line".to_string(),
+        Some(c) if c.is_alphanumeric() => {
+            let end = rest
+                .find(|c: char| !c.is_alphanumeric())
+                .unwrap_or(rest.len());
+            &rest[..end]
+        }
+        Some(c) => &rest[..c.len_utf8()],
+    };
+    format!("{:?}", token)
+}
+
+/// Reads tokens from (part of) an input, keeping track of the position for
+/// error messages.
+#[derive(Debug, Clone, Copy)]
+pub struct Cursor<'a> {
+    input: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Cursor { input, rest: input }
+    }
+
+    /// The text that has not been consumed yet.
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest.chars().next()
+    }
+
+    /// An error at the current position.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        ParseError::at(self.input, self.rest, expected)
+    }
+
+    /// Cursors over each line of the rest, reporting positions in the whole input.
+    pub fn lines(&self) -> impl Iterator<Item = Cursor<'a>> + 'a {
+        let input = self.input;
+        self.rest.lines().map(move |rest| Cursor { input, rest })
+    }
+
+    /// Cursors over each blank-line separated section of the rest.
+    pub fn sections(&self) -> impl Iterator<Item = Cursor<'a>> + 'a {
+        let input = self.input;
+        self.rest
+            .split("\n\n")
+            .map(move |rest| Cursor { input, rest })
+    }
+
+    /// Parses every line of the rest with `parse_line`, which must consume the
+    /// whole line.
+    pub fn parse_lines<T>(
+        &self,
+        mut parse_line: impl FnMut(&mut Cursor<'a>) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        self.lines()
+            .map(|mut line| {
+                let value = parse_line(&mut line)?;
+                line.end()?;
+                Ok(value)
+            })
+            .collect()
+    }
+
+    /// Fails unless everything has been consumed.
+    pub fn end(&self) -> Result<(), ParseError> {
+        if self.is_at_end() {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+
+    /// Consumes `tag`, or fails without consuming anything.
+    pub fn tag(&mut self, tag: &str) -> Result<(), ParseError> {
+        if self.eat(tag) {
+            Ok(())
+        } else {
+            Err(self.error(format!("{:?}", tag)))
+        }
+    }
+
+    /// Consumes `tag` if the rest starts with it.
+    pub fn eat(&mut self, tag: &str) -> bool {
+        match self.rest.strip_prefix(tag) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Consumes characters while `predicate` holds, possibly none.
+    pub fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let end = self
+            .rest
+            .find(|c: char| !predicate(c))
+            .unwrap_or(self.rest.len());
+        let (taken, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        taken
+    }
+
+    /// Consumes everything up to the end of the current line.
+    pub fn take_line(&mut self) -> &'a str {
+        self.take_while(|c| c != '\n')
+    }
+
+    /// Consumes a non-empty run of letters.
+    pub fn word(&mut self) -> Result<&'a str, ParseError> {
+        let start = *self;
+        match self.take_while(char::is_alphabetic) {
+            "" => Err(start.error("a word")),
+            word => Ok(word),
+        }
+    }
+
+    /// Consumes an optionally negative decimal number that fits in `T`.
+    pub fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let start = *self;
+        let negative = self.eat("-");
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        let text = &start.rest[..usize::from(negative) + digits.len()];
+        match text.parse() {
+            Ok(number) if !digits.is_empty() => Ok(number),
+            _ => {
+                *self = start;
+                Err(start.error("a number"))
+            }
+        }
+    }
+
+    /// Consumes one character for which `predicate` holds.
+    pub fn satisfy(
+        &mut self,
+        expected: &str,
+        predicate: impl Fn(char) -> bool,
+    ) -> Result<char, ParseError> {
+        match self.peek() {
+            Some(c) if predicate(c) => {
+                self.rest = &self.rest[c.len_utf8()..];
+                Ok(c)
+            }
+            _ => Err(self.error(expected)),
+        }
+    }
+
+    /// Consumes a single decimal digit.
+    pub fn digit(&mut self) -> Result<u32, ParseError> {
+        match self.peek().and_then(|c| c.to_digit(10)) {
+            Some(digit) => {
+                self.rest = &self.rest[1..];
+                Ok(digit)
+            }
+            None => Err(self.error("a digit")),
+        }
+    }
+
+    /// Consumes one character, mapping it through `options`.
+    pub fn one_of<T: Copy>(&mut self, options: &[(char, T)]) -> Result<T, ParseError> {
+        let next = self.peek();
+        match options.iter().find(|(c, _)| Some(*c) == next) {
+            Some(&(c, value)) => {
+                self.rest = &self.rest[c.len_utf8()..];
+                Ok(value)
+            }
+            None => {
+                let expected = options
+                    .iter()
+                    .map(|(c, _)| format!("{:?}", c))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                Err(self.error(format!("one of {}", expected)))
+            }
+        }
+    }
+}
+
+/// Parses every line of `input` with `parse_line`, which must consume the
+/// whole line.
+pub fn lines<'a, T>(
+    input: &'a str,
+    parse_line: impl FnMut(&mut Cursor<'a>) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    Cursor::new(input).parse_lines(parse_line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn errors_point_at_line_and_column() {
+        let error = lines("1-2\n3+4", |line| {
+            let left = line.number::<u32>()?;
+            line.tag("-")?;
+            Ok((left, line.number::<u32>()?))
+        })
+        .unwrap_err();
+        assert_eq!(
+            error,
+            ParseError {
+                line: 2,
+                column: 2,
+                expected: "\"-\"".to_string(),
+                found: "\"+\"".to_string(),
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected \"-\", found \"+\""
+        );
+    }
+
+    #[test]
+    fn numbers_must_fit_their_type() {
+        let mut cursor = Cursor::new("-3");
+        assert_eq!(cursor.number::<u32>().unwrap_err().expected, "a number");
+        assert_eq!(cursor.number::<i32>(), Ok(-3));
+        assert!(cursor.is_at_end());
+    }
+
+    #[test]
+    fn lines_must_be_fully_consumed() {
+        let error = lines("noop\nnoop extra", |line| line.word()).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(error.expected, "end of line");
+        assert_eq!(error.found, "\" \"");
+    }
+
+    #[test]
+    fn sections_keep_positions_in_the_whole_input() {
+        let cursor = Cursor::new("1\n2\n\n3\nx");
+        let error = cursor
+            .sections()
+            .map(|section| section.parse_lines(|line| line.number::<u32>()))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (5, 1));
+        assert_eq!(error.found, "\"x\"");
+    }
+}
@@ -0,0 +1,142 @@
+use std::{
+    collections::BTreeMap,
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+/// How a task run on the pool ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome<R> {
+    Done(R),
+    /// The task ran past its timeout. It is left running in the background,
+    /// since threads cannot be stopped from the outside.
+    TimedOut,
+    Panicked,
+}
+
+/// Runs `task` on every item, on up to `jobs` items at once, giving up on
+/// any that runs longer than `timeout`.
+///
+/// `report` is called on the calling thread with the index and outcome of
+/// every item, in the order of the items, as soon as all earlier ones are
+/// reported.
+pub fn run<T, R>(
+    items: Vec<T>,
+    jobs: usize,
+    timeout: Option<Duration>,
+    task: impl Fn(T) -> R + Send + Sync + 'static,
+    mut report: impl FnMut(usize, Outcome<R>),
+) where
+    T: Send + 'static,
+    R: Send + 'static,
+{
+    let task = Arc::new(task);
+    let queue = Mutex::new(items.into_iter().enumerate());
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.max(1) {
+            let (queue, task, sender) = (&queue, &task, sender.clone());
+            scope.spawn(move || loop {
+                let Some((index, item)) = queue.lock().expect("queue is never poisoned").next()
+                else {
+                    return;
+                };
+                let outcome = run_task(Arc::clone(task), item, timeout);
+                if sender.send((index, outcome)).is_err() {
+                    return;
+                }
+            });
+        }
+        drop(sender);
+        let mut finished = BTreeMap::new();
+        let mut next = 0;
+        for (index, outcome) in receiver {
+            finished.insert(index, outcome);
+            while let Some(outcome) = finished.remove(&next) {
+                report(next, outcome);
+                next += 1;
+            }
+        }
+    });
+}
+
+/// Runs `task` on `item` on a thread of its own, so it can be abandoned once
+/// it runs past `timeout`.
+fn run_task<T, R>(
+    task: Arc<impl Fn(T) -> R + Send + Sync + 'static>,
+    item: T,
+    timeout: Option<Duration>,
+) -> Outcome<R>
+where
+    T: Send + 'static,
+    R: Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        // The receiver is gone when the task timed out.
+        let _ = sender.send(task(item));
+    });
+    let result = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    };
+    match result {
+        Ok(result) => Outcome::Done(result),
+        Err(RecvTimeoutError::Timeout) => Outcome::TimedOut,
+        Err(RecvTimeoutError::Disconnected) => Outcome::Panicked,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sleeps for the given number of milliseconds, then doubles it.
+    fn sleep_and_double(millis: u64) -> u64 {
+        if millis == 13 {
+            panic!("unlucky");
+        }
+        thread::sleep(Duration::from_millis(millis));
+        millis * 2
+    }
+
+    #[test]
+    fn outcomes_are_reported_in_order() {
+        let mut reported = Vec::new();
+        run(
+            vec![60, 0, 30, 13, 1],
+            3,
+            None,
+            sleep_and_double,
+            |index, outcome| reported.push((index, outcome)),
+        );
+        assert_eq!(
+            reported,
+            [
+                (0, Outcome::Done(120)),
+                (1, Outcome::Done(0)),
+                (2, Outcome::Done(60)),
+                (3, Outcome::Panicked),
+                (4, Outcome::Done(2)),
+            ]
+        );
+    }
+
+    #[test]
+    fn slow_tasks_time_out() {
+        let mut reported = Vec::new();
+        let timeout = Some(Duration::from_millis(200));
+        run(
+            vec![5_000, 1],
+            1,
+            timeout,
+            sleep_and_double,
+            |_, outcome| reported.push(outcome),
+        );
+        assert_eq!(reported, [Outcome::TimedOut, Outcome::Done(2)]);
+    }
+}
@@ -0,0 +1,477 @@
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    input,
+    store::{self, StoreError},
+};
+
+pub const YEAR: u16 = 2022;
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// The environment variable holding the session token, which takes
+/// precedence over the one in the config file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Settings for talking to the puzzle server, kept out of the repository in
+/// a JSON file such as:
+///
+/// ```json
+/// { "session": "53616c7465645f5f...", "base_url": "https://adventofcode.com" }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct Config {
+    /// The value of the `session` cookie of a logged in browser.
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    /// Reads the config at `path`, which is empty when there is no such file.
+    pub fn load(path: &Path) -> Result<Self, StoreError> {
+        Ok(store::load(path)?.unwrap_or_default())
+    }
+
+    /// The session token from the environment, or else from the config.
+    pub fn session(&self) -> Option<String> {
+        env::var(SESSION_VAR)
+            .ok()
+            .or_else(|| self.session.clone())
+            .map(|session| session.trim().to_string())
+            .filter(|session| !session.is_empty())
+    }
+}
+
+/// Talks to the puzzle server at `base_url` on behalf of a logged in user.
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(concat!("aoc-2022/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .new_agent();
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            agent,
+        }
+    }
+
+    fn day_url(&self, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, YEAR, day)
+    }
+
+    fn cookie(&self) -> Result<String, RemoteError> {
+        let session = self.session.as_ref().ok_or(RemoteError::NoSession)?;
+        Ok(format!("session={}", session))
+    }
+
+    /// Downloads the puzzle input of `day`.
+    pub fn input(&self, day: u8) -> Result<String, RemoteError> {
+        let url = format!("{}/input", self.day_url(day));
+        let request_failed = |source| RemoteError::Request {
+            url: url.clone(),
+            source,
+        };
+        let mut response = self
+            .agent
+            .get(&url)
+            .header("Cookie", &self.cookie()?)
+            .call()
+            .map_err(request_failed)?;
+        let status = response.status().as_u16();
+        if status != 200 {
+            return Err(RemoteError::Status { url, status });
+        }
+        response.body_mut().read_to_string().map_err(request_failed)
+    }
+
+    /// Posts `answer` as the answer to `part` of `day`.
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Reply, RemoteError> {
+        let url = format!("{}/answer", self.day_url(day));
+        let request_failed = |source| RemoteError::Request {
+            url: url.clone(),
+            source,
+        };
+        let mut response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &self.cookie()?)
+            .send_form([("level", part.to_string().as_str()), ("answer", answer)])
+            .map_err(request_failed)?;
+        let status = response.status().as_u16();
+        if status != 200 {
+            return Err(RemoteError::Status { url, status });
+        }
+        let page = response
+            .body_mut()
+            .read_to_string()
+            .map_err(request_failed)?;
+        let message = article_text(&page);
+        Reply::parse(&message).ok_or(RemoteError::UnknownReply { url, message })
+    }
+}
+
+/// How the server judged a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Reply {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// The answer was not judged because the previous one was sent too
+    /// recently; the server says how many seconds are left to wait.
+    RateLimited {
+        wait_seconds: Option<u64>,
+    },
+    /// The server answered with a page of no known kind. Only recorded, as
+    /// the client reports it as an error.
+    Unknown,
+    /// The server answered with an error status instead of a verdict. Only
+    /// recorded, as the client reports it as an error.
+    Failed {
+        status: u16,
+    },
+}
+
+impl Reply {
+    /// Reads the verdict from the text of the page answering a submission.
+    pub fn parse(message: &str) -> Option<Reply> {
+        if message.contains("That's the right answer") {
+            Some(Reply::Correct)
+        } else if message.contains("That's not the right answer") {
+            Some(if message.contains("your answer is too high") {
+                Reply::TooHigh
+            } else if message.contains("your answer is too low") {
+                Reply::TooLow
+            } else {
+                Reply::Wrong
+            })
+        } else if message.contains("You gave an answer too recently") {
+            Some(Reply::RateLimited {
+                wait_seconds: parse_wait(message).map(|wait| wait.as_secs()),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Reply {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Reply::Correct => f.write_str("correct"),
+            Reply::Wrong => f.write_str("wrong"),
+            Reply::TooHigh => f.write_str("wrong, too high"),
+            Reply::TooLow => f.write_str("wrong, too low"),
+            Reply::RateLimited {
+                wait_seconds: Some(seconds),
+            } => write!(f, "rate limited, {}s left to wait", seconds),
+            Reply::RateLimited { wait_seconds: None } => f.write_str("rate limited"),
+            Reply::Unknown => f.write_str("not judged, the reply was not understood"),
+            Reply::Failed { status } => {
+                write!(f, "not judged, the server answered with status {}", status)
+            }
+        }
+    }
+}
+
+/// The wait in a message such as "You have 1m 23s left to wait."
+fn parse_wait(message: &str) -> Option<Duration> {
+    let (before, _) = message.split_once(" left to wait")?;
+    let (_, wait) = before.rsplit_once("You have ")?;
+    let mut seconds = 0;
+    for amount in wait.split_whitespace() {
+        let unit_start = amount.find(|c: char| !c.is_ascii_digit())?;
+        let (number, unit) = amount.split_at(unit_start);
+        let number: u64 = number.parse().ok()?;
+        seconds += match unit {
+            "h" => number * 3600,
+            "m" => number * 60,
+            "s" => number,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// The text of the `<article>` of an HTML page, or of the whole page when it
+/// has none, without tags and with whitespace collapsed.
+fn article_text(page: &str) -> String {
+    let article = page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(page, |(article, _)| {
+            article.split_once('>').map_or(article, |(_, text)| text)
+        });
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => {
+                in_tag = false;
+                text.push(' ');
+            }
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Where an input was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already stored, so it was not downloaded again.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Stores the input of `day` as `<inputs_dir>/day<N>/<name>.txt`, downloading
+/// it only when it is not stored yet.
+pub fn fetch_input(
+    client: &Client,
+    inputs_dir: &Path,
+    day: u8,
+    name: &str,
+) -> Result<Fetched, RemoteError> {
+    let path = input::conventional_path(inputs_dir, day, name);
+    if path.exists() {
+        return Ok(Fetched::Cached(path));
+    }
+    let input = client.input(day)?;
+    let unwritable = |source| RemoteError::Unwritable {
+        path: path.clone(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(unwritable)?;
+    }
+    fs::write(&path, input).map_err(unwritable)?;
+    Ok(Fetched::Downloaded(path))
+}
+
+/// Why the puzzle server could not be used.
+#[derive(Debug)]
+pub enum RemoteError {
+    NoSession,
+    Request {
+        url: String,
+        source: ureq::Error,
+    },
+    Status {
+        url: String,
+        status: u16,
+    },
+    /// The server answered a submission with a page of no known kind.
+    UnknownReply {
+        url: String,
+        message: String,
+    },
+    Unwritable {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl Display for RemoteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RemoteError::NoSession => write!(
+                f,
+                "no session token; set {} or add \"session\" to the config file",
+                SESSION_VAR
+            ),
+            RemoteError::Request { url, source } => {
+                write!(f, "request to {} failed: {}", url, source)
+            }
+            RemoteError::Status { url, status } => {
+                write!(f, "{} answered with status {}", url, status)
+            }
+            RemoteError::UnknownReply { url, message } => {
+                write!(f, "{} answered: {}", url, message)
+            }
+            RemoteError::Unwritable { path, source } => {
+                write!(f, "could not write {}: {}", path.display(), source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RemoteError {}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    use super::*;
+
+    /// A request received by a [`MockServer`].
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Received {
+        pub method: String,
+        pub url: String,
+        pub cookie: Option<String>,
+        pub body: String,
+    }
+
+    /// A local HTTP server answering every request with `respond`, and
+    /// remembering what it received.
+    pub struct MockServer {
+        pub base_url: String,
+        pub received: Arc<Mutex<Vec<Received>>>,
+    }
+
+    impl MockServer {
+        pub fn start(respond: impl Fn(&Received) -> (u16, String) + Send + 'static) -> Self {
+            let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+            let port = server.server_addr().to_ip().unwrap().port();
+            let received = Arc::new(Mutex::new(Vec::new()));
+            let log = Arc::clone(&received);
+            thread::spawn(move || {
+                for mut request in server.incoming_requests() {
+                    let mut body = String::new();
+                    request.as_reader().read_to_string(&mut body).unwrap();
+                    let cookie = request
+                        .headers()
+                        .iter()
+                        .find(|header| header.field.equiv("Cookie"))
+                        .map(|header| header.value.to_string());
+                    let received = Received {
+                        method: request.method().to_string(),
+                        url: request.url().to_string(),
+                        cookie,
+                        body,
+                    };
+                    let (status, body) = respond(&received);
+                    log.lock().unwrap().push(received);
+                    let response = tiny_http::Response::from_string(body).with_status_code(status);
+                    let _ = request.respond(response);
+                }
+            });
+            MockServer {
+                base_url: format!("http://127.0.0.1:{}", port),
+                received,
+            }
+        }
+
+        pub fn received(&self) -> Vec<Received> {
+            self.received.lock().unwrap().clone()
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-remote-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn inputs_are_downloaded_once() {
+        let server = MockServer::start(|request| match request.url.as_str() {
+            "/2022/day/1/input" => (200, "1000\n2000\n".to_string()),
+            _ => (404, "Not Found".to_string()),
+        });
+        let client = Client::new(&format!("{}/", server.base_url), Some("abc".to_string()));
+        let dir = temp_dir("cache");
+        let path = input::conventional_path(&dir, 1, "prod");
+        assert_eq!(
+            fetch_input(&client, &dir, 1, "prod").unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+        assert_eq!(
+            fetch_input(&client, &dir, 1, "prod").unwrap(),
+            Fetched::Cached(path)
+        );
+        let received = server.received();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].method, "GET");
+        assert_eq!(received[0].cookie.as_deref(), Some("session=abc"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn failed_downloads_are_not_cached() {
+        let server = MockServer::start(|_| (404, "Not Found".to_string()));
+        let dir = temp_dir("missing");
+        let client = Client::new(&server.base_url, Some("abc".to_string()));
+        let error = fetch_input(&client, &dir, 25, "prod").unwrap_err();
+        assert!(matches!(error, RemoteError::Status { status: 404, .. }));
+        assert!(!input::conventional_path(&dir, 25, "prod").exists());
+        let client = Client::new(&server.base_url, None);
+        assert!(matches!(
+            fetch_input(&client, &dir, 25, "prod"),
+            Err(RemoteError::NoSession)
+        ));
+        assert_eq!(server.received().len(), 1);
+    }
+
+    #[test]
+    fn replies_are_read_from_the_article() {
+        let page = |article: &str| format!("<main><article><p>{}</p></article></main>", article);
+        let reply = |article: &str| Reply::parse(&article_text(&page(article)));
+        assert_eq!(
+            reply("That's the right answer!  You are <em>one gold star</em> closer."),
+            Some(Reply::Correct)
+        );
+        assert_eq!(
+            reply("That's not the right answer; your answer is too high."),
+            Some(Reply::TooHigh)
+        );
+        assert_eq!(
+            reply("That's not the right answer; your answer is too low."),
+            Some(Reply::TooLow)
+        );
+        assert_eq!(
+            reply("That's not the right answer.  If you're stuck, ..."),
+            Some(Reply::Wrong)
+        );
+        assert_eq!(
+            reply("You gave an answer too recently. You have 1m 23s left to wait."),
+            Some(Reply::RateLimited {
+                wait_seconds: Some(83)
+            })
+        );
+        assert_eq!(reply("You don't seem to be solving the right level."), None);
+    }
+
+    #[test]
+    fn answers_are_posted_as_forms() {
+        let server = MockServer::start(|_| {
+            (
+                200,
+                "<article><p>That's the right answer!</p></article>".to_string(),
+            )
+        });
+        let client = Client::new(&server.base_url, Some("abc".to_string()));
+        assert_eq!(client.submit(3, 2, "70").unwrap(), Reply::Correct);
+        let received = server.received();
+        assert_eq!(received[0].method, "POST");
+        assert_eq!(received[0].url, "/2022/day/3/answer");
+        assert_eq!(received[0].body, "level=2&answer=70");
+    }
+
+    #[test]
+    fn configs_may_be_missing() {
+        let config = Config::load(Path::new("no/such/config.json")).unwrap();
+        assert_eq!(config, Config::default());
+    }
+}
@@ -0,0 +1,249 @@
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{fixture, input};
+
+/// The source of a new day: an unsolved [`Solution`] reading the input line
+/// by line, with a test that its example input parses.
+///
+/// [`Solution`]: crate::solution::Solution
+pub fn source(day: u8) -> String {
+    format!(
+        r#"use crate::{{
+    parse::ParseError,
+    solution::{{Answer, Solution}},
+}};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {{
+        Ok(input.lines().collect())
+    }}
+
+    fn part1(_lines: &Self::Input<'_>) -> Answer {{
+        Answer::Unsolved
+    }}
+
+    fn part2(_lines: &Self::Input<'_>) -> Answer {{
+        Answer::Unsolved
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+    use crate::fixture::Fixture;
+
+    #[test]
+    fn example_is_parsed() {{
+        let fixture = Fixture::parse(include_str!("../../fixtures/day{day}/example.txt")).unwrap();
+        assert!(Day{day}::parse(&fixture.input).is_ok());
+    }}
+}}
+"#
+    )
+}
+
+/// An example fixture whose answers are still to be filled in, so both of
+/// its tests start out ignored.
+pub fn example_fixture() -> &'static str {
+    "== part 1 (ignored) ==\n\n== part 2 (ignored) ==\n\n== input ==\n"
+}
+
+/// Registers `day` in the source of `src/days/mod.rs`: declares its module
+/// among the others and adds it to `DAYS` in order of day number.
+pub fn register(days_mod: &str, day: u8) -> Result<String, ScaffoldError> {
+    let not_understood = |what: &str| ScaffoldError::Unregistrable(what.to_string());
+    let module = format!("pub mod day{};", day);
+    let mut lines: Vec<String> = days_mod.lines().map(str::to_string).collect();
+    if lines.contains(&module) {
+        return Err(ScaffoldError::AlreadyRegistered(day));
+    }
+    let modules = lines
+        .iter()
+        .position(|line| line.starts_with("pub mod day"))
+        .ok_or_else(|| not_understood("no `pub mod day<N>;` declarations"))?;
+    let declared = lines[modules..]
+        .iter()
+        .take_while(|line| line.starts_with("pub mod day"))
+        .filter(|line| line.trim_end_matches(';') < module.trim_end_matches(';'))
+        .count();
+    lines.insert(modules + declared, module);
+
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("pub const DAYS: [Day; "))
+        .ok_or_else(|| not_understood("no `DAYS` array"))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line == "];")
+            .ok_or_else(|| not_understood("an unterminated `DAYS` array"))?;
+    let earlier = lines[start + 1..end]
+        .iter()
+        .filter_map(|line| {
+            let (_, number) = line.trim().strip_suffix("),")?.rsplit_once('(')?;
+            number.parse::<u8>().ok()
+        })
+        .filter(|&number| number < day)
+        .count();
+    let count = end - start - 1;
+    lines.insert(
+        start + 1 + earlier,
+        format!("    Day::new::<day{day}::Day{day}>({day}),"),
+    );
+    lines[start] = format!("pub const DAYS: [Day; {}] = [", count + 1);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Creates day `day` in the project at `root`: its source file in
+/// `src/days`, its empty inputs directory, an example fixture, and its
+/// registration with the runner. Returns the paths created.
+pub fn create(root: &Path, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let source_path = root.join("src/days").join(format!("day{}.rs", day));
+    let fixture_path = fixture::path(&root.join("fixtures"), day, "example");
+    for path in [&source_path, &fixture_path] {
+        if path.exists() {
+            return Err(ScaffoldError::Exists(path.clone()));
+        }
+    }
+    let mod_path = root.join("src/days/mod.rs");
+    let days_mod = fs::read_to_string(&mod_path).map_err(|source| ScaffoldError::Io {
+        path: mod_path.clone(),
+        source,
+    })?;
+    let days_mod = register(&days_mod, day)?;
+
+    let inputs_dir = input::conventional_path(&root.join("inputs"), day, "prod")
+        .parent()
+        .expect("inputs are stored in a directory per day")
+        .to_path_buf();
+    let fixtures_dir = fixture_path
+        .parent()
+        .expect("fixtures are stored in a directory per day");
+    for dir in [&inputs_dir, fixtures_dir] {
+        fs::create_dir_all(dir).map_err(|source| ScaffoldError::Io {
+            path: dir.to_path_buf(),
+            source,
+        })?;
+    }
+    let files = [
+        (&source_path, source(day)),
+        (&fixture_path, example_fixture().to_string()),
+        (&mod_path, days_mod),
+    ];
+    for (path, contents) in &files {
+        fs::write(path, contents).map_err(|source| ScaffoldError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+    }
+    Ok(vec![source_path, inputs_dir, fixture_path])
+}
+
+/// Why a day could not be created.
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// A file of the day is already there.
+    Exists(PathBuf),
+    AlreadyRegistered(u8),
+    /// `src/days/mod.rs` does not have the expected layout.
+    Unregistrable(String),
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::Exists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::AlreadyRegistered(day) => write!(f, "day {} is already registered", day),
+            ScaffoldError::Unregistrable(what) => {
+                write!(f, "cannot register the day: src/days/mod.rs has {}", what)
+            }
+            ScaffoldError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A registry laid out like `src/days/mod.rs`, which stays the same as
+    /// days are added to the real one.
+    const DAYS_MOD: &str = "pub mod day1;
+pub mod day10;
+pub mod day2;
+
+use crate::solution::{self, Solution};
+
+pub const DAYS: [Day; 3] = [
+    Day::new::<day1::Day1>(1),
+    Day::new::<day2::Day2>(2),
+    Day::new::<day10::Day10>(10),
+];
+
+pub const GENERATORS: [Generator; 1] = [
+    Generator {
+        day: 1,
+        generate: day1::generate,
+        size: 250,
+    },
+];
+";
+
+    #[test]
+    fn days_are_registered_in_order() {
+        let registered = register(DAYS_MOD, 3).unwrap();
+        let lines: Vec<&str> = registered.lines().collect();
+        let module = lines
+            .iter()
+            .position(|line| *line == "pub mod day3;")
+            .unwrap();
+        assert_eq!(lines[module - 1], "pub mod day2;");
+        assert_eq!(lines[module + 1], "");
+        let day = lines
+            .iter()
+            .position(|line| *line == "    Day::new::<day3::Day3>(3),")
+            .unwrap();
+        assert_eq!(lines[day - 1], "    Day::new::<day2::Day2>(2),");
+        assert_eq!(lines[day + 1], "    Day::new::<day10::Day10>(10),");
+        assert!(registered.contains("pub const DAYS: [Day; 4] = ["));
+        assert!(registered.contains("pub const GENERATORS: [Generator; 1] = ["));
+        assert!(matches!(
+            register(&registered, 3),
+            Err(ScaffoldError::AlreadyRegistered(3))
+        ));
+    }
+
+    #[test]
+    fn new_days_start_unsolved_with_an_example() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::write(root.join("src/days/mod.rs"), DAYS_MOD).unwrap();
+        let created = create(&root, 3).unwrap();
+        assert_eq!(created.len(), 3);
+        assert!(root.join("inputs/day3").is_dir());
+        let fixture = fs::read_to_string(root.join("fixtures/day3/example.txt")).unwrap();
+        let fixture = fixture::Fixture::parse(&fixture).unwrap();
+        assert!(fixture.answers.iter().all(|expected| expected.ignored));
+        let source = fs::read_to_string(root.join("src/days/day3.rs")).unwrap();
+        assert!(source.contains("impl Solution for Day3 {"));
+        let registry = fs::read_to_string(root.join("src/days/mod.rs")).unwrap();
+        assert!(registry.contains("pub mod day3;"));
+        assert!(matches!(create(&root, 3), Err(ScaffoldError::Exists(_))));
+        fs::remove_dir_all(root).unwrap();
+    }
+}
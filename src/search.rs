@@ -0,0 +1,231 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// The cheapest way found from a start state to a goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S> {
+    pub cost: u64,
+    /// Every state on the way, from the start to the goal, both included.
+    pub states: Vec<S>,
+}
+
+/// The states seen by a search, each with the cost of reaching it and the
+/// state it was reached from, so paths can be reconstructed.
+struct Visited<S> {
+    states: Vec<S>,
+    costs: Vec<u64>,
+    parents: Vec<Option<usize>>,
+    indices: HashMap<S, usize>,
+}
+
+impl<S: Clone + Eq + Hash> Visited<S> {
+    fn new(start: S) -> Self {
+        Visited {
+            states: vec![start.clone()],
+            costs: vec![0],
+            parents: vec![None],
+            indices: HashMap::from([(start, 0)]),
+        }
+    }
+
+    /// Records reaching `state` from `parent` at `cost`, unless it was already
+    /// reached as cheaply. Returns the index of the state when it was recorded.
+    fn reach(&mut self, state: S, cost: u64, parent: usize) -> Option<usize> {
+        match self.indices.get(&state) {
+            Some(&index) if self.costs[index] <= cost => None,
+            Some(&index) => {
+                self.costs[index] = cost;
+                self.parents[index] = Some(parent);
+                Some(index)
+            }
+            None => {
+                let index = self.states.len();
+                self.indices.insert(state.clone(), index);
+                self.states.push(state);
+                self.costs.push(cost);
+                self.parents.push(Some(parent));
+                Some(index)
+            }
+        }
+    }
+
+    fn path_to(&self, mut index: usize) -> Path<S> {
+        let cost = self.costs[index];
+        let mut states = vec![self.states[index].clone()];
+        while let Some(parent) = self.parents[index] {
+            states.push(self.states[parent].clone());
+            index = parent;
+        }
+        states.reverse();
+        Path { cost, states }
+    }
+}
+
+/// Breadth-first search for the goal reachable from `start` in the fewest
+/// steps, each step costing 1.
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = Visited::new(start);
+    let mut queue = VecDeque::from([0]);
+    while let Some(index) = queue.pop_front() {
+        if is_goal(&visited.states[index]) {
+            return Some(visited.path_to(index));
+        }
+        let cost = visited.costs[index] + 1;
+        for neighbour in neighbours(&visited.states[index]) {
+            if !visited.indices.contains_key(&neighbour) {
+                queue.extend(visited.reach(neighbour, cost, index));
+            }
+        }
+    }
+    None
+}
+
+/// The fewest steps from `start` to every state reachable from it.
+pub fn distances<S, I>(start: S, mut neighbours: impl FnMut(&S) -> I) -> HashMap<S, u64>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = Visited::new(start);
+    let mut queue = VecDeque::from([0]);
+    while let Some(index) = queue.pop_front() {
+        let cost = visited.costs[index] + 1;
+        for neighbour in neighbours(&visited.states[index]) {
+            if !visited.indices.contains_key(&neighbour) {
+                queue.extend(visited.reach(neighbour, cost, index));
+            }
+        }
+    }
+    visited
+        .indices
+        .into_iter()
+        .map(|(state, index)| (state, visited.costs[index]))
+        .collect()
+}
+
+/// Dijkstra's search for the cheapest goal reachable from `start`, where
+/// `neighbours` yields each next state with the cost of stepping to it.
+pub fn dijkstra<S, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(start, neighbours, |_| 0, is_goal)
+}
+
+/// A* search for the cheapest goal reachable from `start`. `heuristic` must
+/// never overestimate the remaining cost to a goal.
+pub fn astar<S, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u64,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    let mut visited = Visited::new(start);
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        if cost > visited.costs[index] {
+            // A cheaper way to this state was queued since.
+            continue;
+        }
+        if is_goal(&visited.states[index]) {
+            return Some(visited.path_to(index));
+        }
+        for (neighbour, step_cost) in neighbours(&visited.states[index]) {
+            let neighbour_cost = cost + step_cost;
+            let estimate = neighbour_cost + heuristic(&neighbour);
+            if let Some(neighbour) = visited.reach(neighbour, neighbour_cost, index) {
+                queue.push(Reverse((estimate, neighbour_cost, neighbour)));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        grid::{Grid, Position},
+        parse::Cursor,
+    };
+
+    fn maze() -> Grid<bool> {
+        let maze = "..#....\n.##.##.\n....#..\n.##...#\n...#...";
+        Grid::parse(Cursor::new(maze), |cursor| {
+            cursor.one_of(&[('.', true), ('#', false)])
+        })
+        .unwrap()
+    }
+
+    fn open_neighbours(maze: &Grid<bool>, position: Position) -> Vec<Position> {
+        maze.neighbours4(position)
+            .filter(|&neighbour| maze[neighbour])
+            .collect()
+    }
+
+    #[test]
+    fn bfs_finds_a_shortest_path() {
+        let maze = maze();
+        let end = (6, 4);
+        let path = bfs(
+            (0, 0),
+            |&position| open_neighbours(&maze, position),
+            |&p| p == end,
+        )
+        .unwrap();
+        assert_eq!(path.cost, 10);
+        assert_eq!(path.states.len(), 11);
+        assert_eq!(path.states.first(), Some(&(0, 0)));
+        assert_eq!(path.states.last(), Some(&end));
+        assert!(path
+            .states
+            .windows(2)
+            .all(|step| open_neighbours(&maze, step[0]).contains(&step[1])));
+        assert_eq!(bfs((0, 0), |_| [], |&p| p == end), None);
+        let distances = distances((0, 0), |&position| open_neighbours(&maze, position));
+        assert_eq!(distances[&end], 10);
+        assert!(!distances.contains_key(&(2, 0)));
+    }
+
+    #[test]
+    fn dijkstra_and_astar_prefer_cheap_steps() {
+        // Stepping right costs 5, every other step 1.
+        let neighbours = |&(x, y): &(i64, i64)| {
+            [
+                ((x + 1, y), 5),
+                ((x, y + 1), 1),
+                ((x + 1, y + 1), 1),
+                ((x, y - 1), 1),
+            ]
+            .into_iter()
+            .filter(|((x, y), _)| (0..=3).contains(x) && (0..=3).contains(y))
+        };
+        let path = dijkstra((0, 0), neighbours, |&p| p == (3, 0)).unwrap();
+        assert_eq!(path.cost, 6);
+        assert_eq!(path.states.len(), 7);
+        assert_eq!(path.states.last(), Some(&(3, 0)));
+        let heuristic = |&(x, y): &(i64, i64)| (3 - x).max(y.abs()) as u64;
+        let path = astar((0, 0), neighbours, heuristic, |&p| p == (3, 0)).unwrap();
+        assert_eq!(path.cost, 6);
+    }
+}
@@ -0,0 +1,244 @@
+use std::{
+    cell::RefCell,
+    fmt::Display,
+    panic::{self, AssertUnwindSafe, PanicHookInfo},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use crate::parse::ParseError;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    /// The part has not been solved yet.
+    Unsolved,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => f.write_str(text),
+            Answer::Unsolved => f.write_str("unsolved"),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($number:ty),*) => {
+        $(
+            impl From<$number> for Answer {
+                fn from(value: $number) -> Self {
+                    Answer::Number(i64::try_from(value).expect("answer should fit in an i64"))
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// A day's puzzle: a parse step shared by both parts, and the two parts.
+pub trait Solution {
+    /// The parsed puzzle input, which may borrow from the raw input.
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part1(input: &Self::Input<'_>) -> Answer;
+    fn part2(input: &Self::Input<'_>) -> Answer;
+}
+
+/// Why a part could not produce an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    Parse(ParseError),
+    Panicked(String),
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Parse(error) => write!(f, "invalid input: {}", error),
+            Failure::Panicked(message) => write!(f, "panicked: {}", message),
+        }
+    }
+}
+
+pub struct PartRun {
+    pub part: u8,
+    pub answer: Result<Answer, Failure>,
+    pub elapsed: Duration,
+}
+
+/// The timed result of parsing an input once and solving the requested parts.
+pub struct Run {
+    pub parse_elapsed: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+/// Parses `input` with `S` and solves each of `parts` against it.
+///
+/// Panics are caught per step, so a failing part 2 still reports part 1.
+pub fn run<S: Solution>(input: &str, parts: &[u8]) -> Result<Run, Failure> {
+    let start = Instant::now();
+    let parsed = catch(|| S::parse(input))?.map_err(Failure::Parse)?;
+    let parse_elapsed = start.elapsed();
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = catch(|| match part {
+                1 => S::part1(&parsed),
+                2 => S::part2(&parsed),
+                _ => Answer::Unsolved,
+            });
+            PartRun {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect();
+    Ok(Run {
+        parse_elapsed,
+        parts,
+    })
+}
+
+type Hook = Box<dyn Fn(&PanicHookInfo<'_>) + Sync + Send + 'static>;
+
+/// How many threads are solving, and the panic hook their quiet hook replaced.
+static SOLVING: Mutex<(usize, Option<Hook>)> = Mutex::new((0, None));
+
+thread_local! {
+    /// The message of the last panic on this thread while it was solving, or
+    /// `None` when it is not solving.
+    static CAUGHT: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Runs `f`, turning a panic into a failure instead of printing it.
+///
+/// The panic hook is process-wide and days are solved on several threads, so
+/// the first thread to start solving installs a quiet hook and the last one to
+/// finish restores the original. Panics on threads that are not solving still
+/// go to the original hook.
+fn catch<T>(f: impl FnOnce() -> T) -> Result<T, Failure> {
+    {
+        let mut solving = SOLVING.lock().unwrap_or_else(|error| error.into_inner());
+        if solving.0 == 0 {
+            solving.1 = Some(panic::take_hook());
+            panic::set_hook(Box::new(quiet_hook));
+        }
+        solving.0 += 1;
+    }
+    CAUGHT.with(|caught| *caught.borrow_mut() = Some(String::new()));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    let message = CAUGHT.with(|caught| caught.borrow_mut().take());
+    {
+        let mut solving = SOLVING.lock().unwrap_or_else(|error| error.into_inner());
+        solving.0 -= 1;
+        if solving.0 == 0 {
+            if let Some(original) = solving.1.take() {
+                panic::set_hook(original);
+            }
+        }
+    }
+    result.map_err(|_| Failure::Panicked(message.unwrap_or_default()))
+}
+
+fn quiet_hook(info: &PanicHookInfo<'_>) {
+    let solving = CAUGHT.with(|caught| match caught.borrow_mut().as_mut() {
+        Some(message) => {
+            *message = info.payload_as_str().unwrap_or("unknown panic").to_string();
+            true
+        }
+        None => false,
+    });
+    if !solving {
+        let solving = SOLVING.lock().unwrap_or_else(|error| error.into_inner());
+        if let Some(original) = &solving.1 {
+            original(info);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Lines;
+
+    impl Solution for Lines {
+        type Input<'a> = Vec<&'a str>;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            let lines: Vec<&str> = input.lines().collect();
+            match lines.iter().position(|line| line.is_empty()) {
+                Some(index) => Err(ParseError {
+                    line: index + 1,
+                    column: 1,
+                    expected: "a line".to_string(),
+                    found: "end of line".to_string(),
+                }),
+                None => Ok(lines),
+            }
+        }
+
+        fn part1(input: &Self::Input<'_>) -> Answer {
+            input.len().into()
+        }
+
+        fn part2(_input: &Self::Input<'_>) -> Answer {
+            panic!("no part 2")
+        }
+    }
+
+    #[test]
+    fn run_solves_each_part() {
+        let run = run::<Lines>("a\nb\nc", &[1, 2]).unwrap();
+        assert_eq!(run.parts[0].answer, Ok(Answer::Number(3)));
+        assert_eq!(
+            run.parts[1].answer,
+            Err(Failure::Panicked("no part 2".to_string()))
+        );
+    }
+
+    #[test]
+    fn caught_panics_keep_their_message() {
+        assert_eq!(
+            catch(|| -> u8 { panic!("{} rocks", 42) }),
+            Err(Failure::Panicked("42 rocks".to_string()))
+        );
+        assert_eq!(catch(|| 42), Ok(42));
+    }
+
+    #[test]
+    fn run_reports_parse_errors() {
+        let Err(Failure::Parse(error)) = run::<Lines>("a\n\nc", &[1, 2]) else {
+            panic!("empty line should not parse");
+        };
+        assert_eq!(error.line, 2);
+    }
+
+    #[test]
+    fn answers_display_their_value() {
+        assert_eq!(Answer::from(42u32).to_string(), "42");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+        assert_eq!(Answer::Unsolved.to_string(), "unsolved");
+    }
+}
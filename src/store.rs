@@ -0,0 +1,71 @@
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Why a JSON file kept alongside the inputs could not be read or written.
+#[derive(Debug)]
+pub enum StoreError {
+    Unreadable {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Invalid {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+    Unwritable {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl Display for StoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StoreError::Unreadable { path, source } => {
+                write!(f, "could not read {}: {}", path.display(), source)
+            }
+            StoreError::Invalid { path, source } => {
+                write!(f, "invalid JSON in {}: {}", path.display(), source)
+            }
+            StoreError::Unwritable { path, source } => {
+                write!(f, "could not write {}: {}", path.display(), source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for StoreError {}
+
+/// Reads the value stored at `path`, or `None` when there is no such file.
+pub fn load<T: DeserializeOwned>(path: &Path) -> Result<Option<T>, StoreError> {
+    let json = match fs::read_to_string(path) {
+        Ok(json) => json,
+        Err(source) if source.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(source) => {
+            return Err(StoreError::Unreadable {
+                path: path.to_path_buf(),
+                source,
+            })
+        }
+    };
+    serde_json::from_str(&json)
+        .map(Some)
+        .map_err(|source| StoreError::Invalid {
+            path: path.to_path_buf(),
+            source,
+        })
+}
+
+/// Writes `value` to `path` as pretty-printed JSON.
+pub fn save<T: Serialize>(path: &Path, value: &T) -> Result<(), StoreError> {
+    let json = serde_json::to_string_pretty(value).expect("value serializes to JSON");
+    fs::write(path, json + "\n").map_err(|source| StoreError::Unwritable {
+        path: path.to_path_buf(),
+        source,
+    })
+}
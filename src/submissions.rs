@@ -0,0 +1,283 @@
+use std::{fmt::Display, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    remote::{Client, RemoteError, Reply},
+    store::{self, StoreError},
+};
+
+/// An answer sent to the server, and how it was judged.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub reply: Reply,
+}
+
+/// Every answer submitted so far, kept so that answers the server already
+/// rejected are not sent again.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Submissions(Vec<Submission>);
+
+impl Submissions {
+    /// Reads the submissions stored at `path`; a missing file holds none.
+    pub fn load(path: &Path) -> Result<Self, StoreError> {
+        Ok(Submissions(store::load(path)?.unwrap_or_default()))
+    }
+
+    /// Writes the submissions to `path`, in the order they were sent.
+    pub fn save(&self, path: &Path) -> Result<(), StoreError> {
+        store::save(path, &self.0)
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, answer: &str, reply: Reply) {
+        self.0.push(Submission {
+            day,
+            part,
+            answer: answer.to_string(),
+            reply,
+        });
+    }
+
+    /// Checks `answer` against the earlier submissions of the same part,
+    /// refusing it when it is known to be wrong, when it lies outside the
+    /// bounds set by answers that were too high or too low, or when the
+    /// part is already solved.
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Result<(), Refusal> {
+        let earlier = || {
+            self.0
+                .iter()
+                .filter(move |submission| submission.day == day && submission.part == part)
+        };
+        if let Some(correct) = earlier().find(|submission| submission.reply == Reply::Correct) {
+            return Err(Refusal::Solved {
+                answer: correct.answer.clone(),
+            });
+        }
+        if earlier().any(|submission| {
+            submission.answer == answer
+                && matches!(
+                    submission.reply,
+                    Reply::Wrong | Reply::TooHigh | Reply::TooLow
+                )
+        }) {
+            return Err(Refusal::KnownWrong);
+        }
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+        let bound = |reply| {
+            earlier()
+                .filter(move |submission| submission.reply == reply)
+                .filter_map(|submission| {
+                    Some((submission.answer.parse::<i128>().ok()?, submission))
+                })
+        };
+        if let Some((_, high)) = bound(Reply::TooHigh)
+            .filter(|&(high, _)| value >= high)
+            .min_by_key(|&(high, _)| high)
+        {
+            return Err(Refusal::TooHigh {
+                bound: high.answer.clone(),
+            });
+        }
+        if let Some((_, low)) = bound(Reply::TooLow)
+            .filter(|&(low, _)| value <= low)
+            .max_by_key(|&(low, _)| low)
+        {
+            return Err(Refusal::TooLow {
+                bound: low.answer.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Every submission, in the order they were sent.
+    pub fn iter(&self) -> impl Iterator<Item = &Submission> {
+        self.0.iter()
+    }
+}
+
+/// Why an answer was not sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    /// The part was already solved with `answer`.
+    Solved { answer: String },
+    /// The same answer was already rejected.
+    KnownWrong,
+    /// The answer is not below an answer that was too high.
+    TooHigh { bound: String },
+    /// The answer is not above an answer that was too low.
+    TooLow { bound: String },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::Solved { answer } => write!(f, "already solved with {}", answer),
+            Refusal::KnownWrong => f.write_str("already submitted and wrong"),
+            Refusal::TooHigh { bound } => write!(f, "too high, as {} already was", bound),
+            Refusal::TooLow { bound } => write!(f, "too low, as {} already was", bound),
+        }
+    }
+}
+
+/// Why an answer could not be submitted.
+#[derive(Debug)]
+pub enum SubmitError {
+    Refused(Refusal),
+    Remote(RemoteError),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitError::Refused(refusal) => write!(f, "not submitted: {}", refusal),
+            SubmitError::Remote(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+/// Sends `answer` to the server unless the earlier submissions show it is
+/// wrong, and records how the server judged it. A reply that is not
+/// understood or an error status is recorded too before being returned as
+/// an error; an answer that may not have reached the server is not.
+pub fn submit(
+    client: &Client,
+    submissions: &mut Submissions,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Reply, SubmitError> {
+    submissions
+        .check(day, part, answer)
+        .map_err(SubmitError::Refused)?;
+    let result = client.submit(day, part, answer);
+    let recorded = match &result {
+        Ok(reply) => Some(*reply),
+        Err(RemoteError::UnknownReply { .. }) => Some(Reply::Unknown),
+        Err(RemoteError::Status { status, .. }) => Some(Reply::Failed { status: *status }),
+        Err(_) => None,
+    };
+    if let Some(reply) = recorded {
+        submissions.record(day, part, answer, reply);
+    }
+    result.map_err(SubmitError::Remote)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::remote::tests::MockServer;
+
+    /// A stand-in server whose answer to part 1 of every day is 42.
+    fn server() -> MockServer {
+        MockServer::start(|request| {
+            let answer: i64 = request
+                .body
+                .split_once("answer=")
+                .and_then(|(_, answer)| answer.parse().ok())
+                .unwrap_or(0);
+            let message = match answer.cmp(&42) {
+                std::cmp::Ordering::Equal => "That's the right answer!",
+                std::cmp::Ordering::Less => "That's not the right answer; your answer is too low.",
+                std::cmp::Ordering::Greater => {
+                    "That's not the right answer; your answer is too high."
+                }
+            };
+            (200, format!("<article><p>{}</p></article>", message))
+        })
+    }
+
+    #[test]
+    fn answers_outside_known_bounds_are_not_sent() {
+        let server = server();
+        let client = Client::new(&server.base_url, Some("abc".to_string()));
+        let mut submissions = Submissions::default();
+        let mut submit = |answer| submit(&client, &mut submissions, 1, 1, answer);
+        assert_eq!(submit("50").unwrap(), Reply::TooHigh);
+        assert_eq!(submit("10").unwrap(), Reply::TooLow);
+        assert!(matches!(
+            submit("50"),
+            Err(SubmitError::Refused(Refusal::KnownWrong))
+        ));
+        assert!(matches!(
+            submit("60"),
+            Err(SubmitError::Refused(Refusal::TooHigh { bound })) if bound == "50"
+        ));
+        assert!(matches!(
+            submit("3"),
+            Err(SubmitError::Refused(Refusal::TooLow { bound })) if bound == "10"
+        ));
+        assert_eq!(submit("42").unwrap(), Reply::Correct);
+        assert!(matches!(
+            submit("43"),
+            Err(SubmitError::Refused(Refusal::Solved { answer })) if answer == "42"
+        ));
+        assert_eq!(server.received().len(), 3);
+    }
+
+    #[test]
+    fn unjudged_submissions_are_recorded() {
+        let server = MockServer::start(|request| match request.body.as_str() {
+            "level=1&answer=1" => (500, "Internal Server Error".to_string()),
+            _ => (200, "<article><p>Something else.</p></article>".to_string()),
+        });
+        let client = Client::new(&server.base_url, Some("abc".to_string()));
+        let mut submissions = Submissions::default();
+        assert!(matches!(
+            submit(&client, &mut submissions, 1, 1, "1"),
+            Err(SubmitError::Remote(RemoteError::Status { status: 500, .. }))
+        ));
+        assert!(matches!(
+            submit(&client, &mut submissions, 1, 1, "2"),
+            Err(SubmitError::Remote(RemoteError::UnknownReply { .. }))
+        ));
+        let replies: Vec<(&str, Reply)> = submissions
+            .iter()
+            .map(|submission| (submission.answer.as_str(), submission.reply))
+            .collect();
+        assert_eq!(
+            replies,
+            [("1", Reply::Failed { status: 500 }), ("2", Reply::Unknown)]
+        );
+        assert_eq!(submissions.check(1, 1, "1"), Ok(()));
+    }
+
+    #[test]
+    fn rate_limited_answers_may_be_sent_again() {
+        let mut submissions = Submissions::default();
+        submissions.record(5, 1, "CMZ", Reply::RateLimited { wait_seconds: None });
+        assert_eq!(submissions.check(5, 1, "CMZ"), Ok(()));
+        submissions.record(5, 1, "CMZ", Reply::Wrong);
+        assert_eq!(submissions.check(5, 1, "CMZ"), Err(Refusal::KnownWrong));
+        assert_eq!(submissions.check(5, 1, "MCD"), Ok(()));
+        assert_eq!(submissions.check(5, 2, "CMZ"), Ok(()));
+    }
+
+    #[test]
+    fn submissions_round_trip() {
+        let path =
+            std::env::temp_dir().join(format!("aoc-submissions-{}.json", std::process::id()));
+        let mut submissions = Submissions::default();
+        submissions.record(1, 1, "24000", Reply::TooLow);
+        submissions.record(
+            1,
+            1,
+            "25000",
+            Reply::RateLimited {
+                wait_seconds: Some(30),
+            },
+        );
+        submissions.save(&path).unwrap();
+        let loaded = Submissions::load(&path);
+        fs::remove_file(path).unwrap();
+        assert_eq!(loaded.unwrap(), submissions);
+    }
+}
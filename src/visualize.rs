@@ -0,0 +1,459 @@
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+use crate::{
+    geometry::Point2,
+    grid::{Grid, Position},
+};
+
+/// A colour as red, green and blue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+/// Parses `RRGGBB` in hexadecimal, optionally preceded by `#`.
+impl FromStr for Rgb {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s.strip_prefix('#').unwrap_or(s);
+        let channel = |i: usize| {
+            hex.get(i..i + 2)
+                .and_then(|channel| u8::from_str_radix(channel, 16).ok())
+        };
+        match (hex.len(), channel(0), channel(2), channel(4)) {
+            (6, Some(red), Some(green), Some(blue)) => Ok(Rgb(red, green, blue)),
+            _ => Err(format!("expected a colour as RRGGBB, found {:?}", s)),
+        }
+    }
+}
+
+/// The colour of every kind of tile in a frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    /// The colour around frames smaller than the picture.
+    background: Rgb,
+    /// The colour of tiles without one of their own.
+    fallback: Rgb,
+    tiles: Vec<(char, Rgb)>,
+}
+
+impl Palette {
+    pub fn new(background: Rgb, tiles: &[(char, Rgb)]) -> Self {
+        Palette {
+            background,
+            fallback: Rgb(255, 255, 255),
+            tiles: tiles.to_vec(),
+        }
+    }
+
+    /// Gives `tile` its own colour, replacing any it had.
+    pub fn set(&mut self, tile: char, colour: Rgb) {
+        match self.tiles.iter_mut().find(|(known, _)| *known == tile) {
+            Some((_, known)) => *known = colour,
+            None => self.tiles.push((tile, colour)),
+        }
+    }
+
+    pub fn colour(&self, tile: char) -> Rgb {
+        self.tiles
+            .iter()
+            .find(|&&(known, _)| known == tile)
+            .map_or(self.fallback, |&(_, colour)| colour)
+    }
+
+    /// Every colour, indexed as by `index`: the background, the fallback,
+    /// then the tiles.
+    fn colours(&self) -> Vec<Rgb> {
+        [self.background, self.fallback]
+            .into_iter()
+            .chain(self.tiles.iter().map(|&(_, colour)| colour))
+            .collect()
+    }
+
+    /// The index in `colours` of the colour of `tile`, or of the background
+    /// when there is no tile.
+    fn index(&self, tile: Option<char>) -> usize {
+        let Some(tile) = tile else { return 0 };
+        self.tiles
+            .iter()
+            .position(|&(known, _)| known == tile)
+            .map_or(1, |index| index + 2)
+    }
+}
+
+/// One picture of a simulation, with a character per tile as in its
+/// `Display` output. `origin` is where the top left tile lies in the
+/// simulation, so frames of different sizes line up in an animation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub origin: Point2,
+    pub tiles: Grid<char>,
+}
+
+/// A simulation that can be recorded frame by frame. Its `Display` output
+/// shows the current state as text.
+pub trait Simulation: Display {
+    /// The current state.
+    fn frame(&self) -> Frame;
+
+    /// Advances the simulation by one step. Returns false, leaving the state
+    /// as it is, once the simulation is over.
+    fn step(&mut self) -> bool;
+}
+
+/// Records the first frame of `simulation` and one frame every `every`
+/// steps, up to `limit` steps if given. The last state is always recorded.
+pub fn record(simulation: &mut dyn Simulation, every: usize, limit: Option<usize>) -> Vec<Frame> {
+    let mut frames = vec![simulation.frame()];
+    let mut steps = 0;
+    let mut recorded = true;
+    while limit.is_none_or(|limit| steps < limit) && simulation.step() {
+        steps += 1;
+        recorded = steps % every == 0;
+        if recorded {
+            frames.push(simulation.frame());
+        }
+    }
+    if !recorded {
+        frames.push(simulation.frame());
+    }
+    frames
+}
+
+/// The smallest area holding every frame of an animation, in tiles.
+struct Canvas {
+    top_left: Point2,
+    width: usize,
+    height: usize,
+}
+
+impl Canvas {
+    fn around(frames: &[Frame]) -> Self {
+        let corners = || {
+            frames.iter().map(|frame| {
+                let size = Point2::new(frame.tiles.width() as i64, frame.tiles.height() as i64);
+                (frame.origin, frame.origin + size)
+            })
+        };
+        let top_left = corners().fold(Point2::new(i64::MAX, i64::MAX), |corner, (top_left, _)| {
+            Point2::new(corner.x.min(top_left.x), corner.y.min(top_left.y))
+        });
+        let bottom_right = corners().fold(top_left, |corner, (_, bottom_right)| {
+            Point2::new(corner.x.max(bottom_right.x), corner.y.max(bottom_right.y))
+        });
+        Canvas {
+            top_left,
+            width: (bottom_right.x - top_left.x) as usize,
+            height: (bottom_right.y - top_left.y) as usize,
+        }
+    }
+
+    /// The size of the picture in pixels, `scale` pixels wide and high per tile.
+    fn pixel_size(&self, scale: usize) -> (usize, usize) {
+        (self.width * scale, self.height * scale)
+    }
+
+    /// What `paint` makes of the tile under every pixel of `frame`, row by
+    /// row, where pixels outside the frame have no tile.
+    fn pixels<P>(&self, frame: &Frame, scale: usize, paint: impl Fn(Option<char>) -> P) -> Vec<P> {
+        let (width, height) = self.pixel_size(scale);
+        let offset = frame.origin - self.top_left;
+        let mut pixels = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                let tile = Point2::new((x / scale) as i64, (y / scale) as i64) - offset;
+                let tile = Position::try_from(tile)
+                    .ok()
+                    .and_then(|tile| frame.tiles.get(tile));
+                pixels.push(paint(tile.copied()));
+            }
+        }
+        pixels
+    }
+
+    /// The red, green and blue bytes of every pixel of `frame`, row by row.
+    fn rgb(&self, frame: &Frame, palette: &Palette, scale: usize) -> Vec<u8> {
+        self.pixels(frame, scale, |tile| {
+            let Rgb(red, green, blue) =
+                tile.map_or(palette.background, |tile| palette.colour(tile));
+            [red, green, blue]
+        })
+        .concat()
+    }
+}
+
+/// Writes `frame` as a binary PPM image.
+pub fn write_ppm(
+    out: &mut impl Write,
+    frame: &Frame,
+    palette: &Palette,
+    scale: usize,
+) -> io::Result<()> {
+    let canvas = Canvas::around(std::slice::from_ref(frame));
+    let (width, height) = canvas.pixel_size(scale);
+    write!(out, "P6\n{} {}\n255\n", width, height)?;
+    out.write_all(&canvas.rgb(frame, palette, scale))
+}
+
+pub fn write_png(
+    out: &mut impl Write,
+    frame: &Frame,
+    palette: &Palette,
+    scale: usize,
+) -> Result<(), ExportError> {
+    let canvas = Canvas::around(std::slice::from_ref(frame));
+    let (width, height) = canvas.pixel_size(scale);
+    let too_large = || ExportError::TooLarge { width, height };
+    let mut encoder = png::Encoder::new(
+        out,
+        u32::try_from(width).map_err(|_| too_large())?,
+        u32::try_from(height).map_err(|_| too_large())?,
+    );
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(ExportError::Png)?;
+    writer
+        .write_image_data(&canvas.rgb(frame, palette, scale))
+        .map_err(ExportError::Png)?;
+    writer.finish().map_err(ExportError::Png)
+}
+
+/// Writes `frames` as an animated GIF that loops forever, showing each frame
+/// for `delay`.
+pub fn write_gif(
+    out: &mut impl Write,
+    frames: &[Frame],
+    palette: &Palette,
+    scale: usize,
+    delay: Duration,
+) -> Result<(), ExportError> {
+    let colours = palette.colours();
+    if colours.len() > 256 {
+        return Err(ExportError::TooManyColours(colours.len()));
+    }
+    let canvas = Canvas::around(frames);
+    let (width, height) = canvas.pixel_size(scale);
+    let too_large = || ExportError::TooLarge { width, height };
+    let gif_width = u16::try_from(width).map_err(|_| too_large())?;
+    let gif_height = u16::try_from(height).map_err(|_| too_large())?;
+    let global_palette: Vec<u8> = colours
+        .iter()
+        .flat_map(|&Rgb(red, green, blue)| [red, green, blue])
+        .collect();
+    let mut encoder =
+        gif::Encoder::new(out, gif_width, gif_height, &global_palette).map_err(ExportError::Gif)?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(ExportError::Gif)?;
+    let centiseconds = u16::try_from(delay.as_millis() / 10).unwrap_or(u16::MAX);
+    for frame in frames {
+        let pixels = canvas.pixels(frame, scale, |tile| palette.index(tile) as u8);
+        let mut frame = gif::Frame::from_indexed_pixels(gif_width, gif_height, pixels, None);
+        frame.delay = centiseconds;
+        encoder.write_frame(&frame).map_err(ExportError::Gif)?;
+    }
+    Ok(())
+}
+
+/// The kinds of image that can be exported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+    Gif,
+}
+
+impl ImageFormat {
+    /// The format matching the extension of `path`.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "ppm" => Some(ImageFormat::Ppm),
+            "png" => Some(ImageFormat::Png),
+            "gif" => Some(ImageFormat::Gif),
+            _ => None,
+        }
+    }
+}
+
+/// How to draw the recorded frames.
+#[derive(Debug, Clone)]
+pub struct ExportOptions {
+    pub palette: Palette,
+    /// Pixels per tile, both wide and high.
+    pub scale: usize,
+    /// How long each frame of an animation is shown.
+    pub delay: Duration,
+}
+
+/// Writes `frames` to `path` in the format matching its extension: a still
+/// of the last frame for `.ppm` and `.png`, or an animation for `.gif`.
+pub fn export(path: &Path, frames: &[Frame], options: &ExportOptions) -> Result<(), ExportError> {
+    let format = ImageFormat::from_path(path)
+        .ok_or_else(|| ExportError::UnknownFormat(path.to_path_buf()))?;
+    let last = frames.last().ok_or(ExportError::NoFrames)?;
+    let unwritable = |source| ExportError::Unwritable {
+        path: path.to_path_buf(),
+        source,
+    };
+    let mut out = BufWriter::new(File::create(path).map_err(unwritable)?);
+    let ExportOptions {
+        palette,
+        scale,
+        delay,
+    } = options;
+    match format {
+        ImageFormat::Ppm => write_ppm(&mut out, last, palette, *scale).map_err(unwritable)?,
+        ImageFormat::Png => write_png(&mut out, last, palette, *scale)?,
+        ImageFormat::Gif => write_gif(&mut out, frames, palette, *scale, *delay)?,
+    }
+    out.flush().map_err(unwritable)
+}
+
+/// Why frames could not be exported.
+#[derive(Debug)]
+pub enum ExportError {
+    UnknownFormat(PathBuf),
+    NoFrames,
+    TooLarge { width: usize, height: usize },
+    TooManyColours(usize),
+    Unwritable { path: PathBuf, source: io::Error },
+    Png(png::EncodingError),
+    Gif(gif::EncodingError),
+}
+
+impl Display for ExportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExportError::UnknownFormat(path) => write!(
+                f,
+                "unknown image format for {}; expected .ppm, .png or .gif",
+                path.display()
+            ),
+            ExportError::NoFrames => f.write_str("no frames to export"),
+            ExportError::TooLarge { width, height } => {
+                write!(
+                    f,
+                    "a {}x{} picture is too large for the format",
+                    width, height
+                )
+            }
+            ExportError::TooManyColours(colours) => {
+                write!(f, "{} colours do not fit in a GIF palette of 256", colours)
+            }
+            ExportError::Unwritable { path, source } => {
+                write!(f, "could not write {}: {}", path.display(), source)
+            }
+            ExportError::Png(error) => write!(f, "could not encode PNG: {}", error),
+            ExportError::Gif(error) => write!(f, "could not encode GIF: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for ExportError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLACK: Rgb = Rgb(0, 0, 0);
+    const RED: Rgb = Rgb(255, 0, 0);
+
+    fn frame(origin: Point2, rows: &[&str]) -> Frame {
+        let mut tiles = Grid::new(rows[0].len(), rows.len(), '.');
+        for (y, row) in rows.iter().enumerate() {
+            for (x, tile) in row.chars().enumerate() {
+                tiles[(x, y)] = tile;
+            }
+        }
+        Frame { origin, tiles }
+    }
+
+    /// Counts up to 10, one step at a time.
+    struct Counter(usize);
+
+    impl Display for Counter {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl Simulation for Counter {
+        fn frame(&self) -> Frame {
+            frame(Point2::new(self.0 as i64, 0), &["#"])
+        }
+
+        fn step(&mut self) -> bool {
+            if self.0 == 10 {
+                return false;
+            }
+            self.0 += 1;
+            true
+        }
+    }
+
+    #[test]
+    fn colours_are_parsed_and_assigned_to_tiles() {
+        assert_eq!("#ff8000".parse(), Ok(Rgb(255, 128, 0)));
+        assert_eq!("00FF00".parse(), Ok(Rgb(0, 255, 0)));
+        assert!("fff".parse::<Rgb>().is_err());
+        let mut palette = Palette::new(BLACK, &[('#', RED)]);
+        assert_eq!(palette.colour('#'), RED);
+        assert_eq!(palette.colour('?'), Rgb(255, 255, 255));
+        palette.set('#', BLACK);
+        assert_eq!(palette.colour('#'), BLACK);
+    }
+
+    #[test]
+    fn every_nth_frame_and_the_last_one_are_recorded() {
+        let origins = |frames: Vec<Frame>| {
+            frames
+                .iter()
+                .map(|frame| frame.origin.x)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(origins(record(&mut Counter(0), 4, None)), [0, 4, 8, 10]);
+        assert_eq!(origins(record(&mut Counter(0), 5, None)), [0, 5, 10]);
+        assert_eq!(origins(record(&mut Counter(0), 1, Some(2))), [0, 1, 2]);
+    }
+
+    #[test]
+    fn stills_are_scaled_tile_by_tile() {
+        let palette = Palette::new(BLACK, &[('#', RED), ('.', BLACK)]);
+        let mut ppm = Vec::new();
+        write_ppm(&mut ppm, &frame(Point2::ORIGIN, &["#."]), &palette, 2).unwrap();
+        let red_black = [255, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(
+            ppm,
+            [b"P6\n4 2\n255\n".as_slice(), &red_black, &red_black].concat()
+        );
+        let mut png = Vec::new();
+        write_png(&mut png, &frame(Point2::ORIGIN, &["#."]), &palette, 2).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+    }
+
+    #[test]
+    fn animations_cover_every_frame() {
+        let frames = [
+            frame(Point2::new(-1, 0), &["#"]),
+            frame(Point2::new(1, 2), &["##"]),
+        ];
+        let canvas = Canvas::around(&frames);
+        assert_eq!(
+            (canvas.top_left, canvas.width, canvas.height),
+            (Point2::new(-1, 0), 4, 3)
+        );
+        let palette = Palette::new(BLACK, &[('#', RED)]);
+        let indices = canvas.pixels(&frames[1], 1, |tile| palette.index(tile));
+        assert_eq!(indices[8..], [0, 0, 2, 2]);
+        let mut gif = Vec::new();
+        write_gif(&mut gif, &frames, &palette, 3, Duration::from_millis(100)).unwrap();
+        assert!(gif.starts_with(b"GIF89a"));
+        assert_eq!(gif[6..10], [12, 0, 9, 0]);
+    }
+}